@@ -0,0 +1,17 @@
+//! What the benchmarks share.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Runs `run` three times and returns its result with the fastest time.
+pub fn time<T>(mut run: impl FnMut() -> T) -> (T, Duration) {
+    let mut best = Duration::MAX;
+    let mut result = None;
+    for _ in 0..3 {
+        let start = Instant::now();
+        result = Some(black_box(run()));
+        best = best.min(start.elapsed());
+    }
+
+    (result.unwrap(), best)
+}
@@ -0,0 +1,84 @@
+//! Compares trebuchet part 2 against the original per-character scanner on a
+//! large generated calibration document.
+//!
+//! Run with `cargo bench -p aoc2023 --bench trebuchet [-- LINES]`.
+
+mod common;
+
+use aoc2023::trebuchet::generator::Generator;
+use aoc2023::Trebuchet;
+use aoc_core::{Answer, Solution};
+
+use common::time;
+
+const NUMBERS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+const NUMBER_LETTERS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The scanner trebuchet part 2 used before the automaton: it grows a prefix
+/// one character at a time and searches it, and a reversed copy, for every
+/// spelling, then does the same again on the reversed line.
+fn naive_first_line_digit(input: &str) -> Option<usize> {
+    let mut temp_string = String::new();
+
+    for item in input.chars() {
+        temp_string.push(item);
+
+        for index in 0..10 {
+            if temp_string.contains(NUMBERS[index]) || temp_string.contains(NUMBER_LETTERS[index]) {
+                return Some(index);
+            }
+
+            let reversed_string = temp_string.chars().rev().collect::<String>();
+            if reversed_string.contains(NUMBERS[index])
+                || reversed_string.contains(NUMBER_LETTERS[index])
+            {
+                return Some(index);
+            }
+        }
+    }
+
+    None
+}
+
+fn naive_sum(input: &str) -> usize {
+    input
+        .lines()
+        .map(|line| {
+            let first = naive_first_line_digit(line).unwrap();
+            let last = naive_first_line_digit(&line.chars().rev().collect::<String>()).unwrap();
+            first * 10 + last
+        })
+        .sum()
+}
+
+fn main() {
+    let lines = std::env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse().ok())
+        .unwrap_or(100_000);
+    let document = Generator::new(0x2545_f491_4f6c_dd1d).document(lines);
+    let input = document.text;
+    println!(
+        "trebuchet part 2 on {lines} generated lines ({:.1} MiB), best of 3",
+        input.len() as f64 / (1024.0 * 1024.0)
+    );
+
+    let (expected, naive) = time(|| naive_sum(&input));
+    println!("  per-character scan: {naive:>10.2?}");
+
+    let parsed = Trebuchet::parse(&input).unwrap();
+    let (actual, automaton) = time(|| Trebuchet::part2(&parsed).unwrap());
+    println!("  automaton:          {automaton:>10.2?}");
+
+    assert_eq!(
+        expected as u64, document.part2,
+        "the per-character scan is off"
+    );
+    assert_eq!(actual, Answer::from(expected), "the two scanners disagree");
+    println!(
+        "  speedup:            {:>9.1}x",
+        naive.as_secs_f64() / automaton.as_secs_f64()
+    );
+}
@@ -0,0 +1,73 @@
+//! Compares the regular trebuchet parts against the whole-buffer byte scanner
+//! on a generated calibration document of several hundred megabytes.
+//!
+//! Run with `cargo bench -p aoc2023 --bench trebuchet_bytes [-- MIB]`.
+
+mod common;
+
+use std::time::Duration;
+
+use aoc2023::trebuchet::bytes;
+use aoc2023::trebuchet::generator::Generator;
+use aoc2023::Trebuchet;
+use aoc_core::{Answer, Solution};
+
+use common::time;
+
+/// A document of about `size` bytes, and its answers.
+fn generate(size: usize) -> (String, u64, u64) {
+    let mut generator = Generator::new(0x2545_f491_4f6c_dd1d);
+    let mut document = String::with_capacity(size + 128);
+    let (mut part1, mut part2) = (0, 0);
+
+    while document.len() < size {
+        let line = generator.line();
+        document.push_str(&line.text);
+        document.push('\n');
+        part1 += line.part1;
+        part2 += line.part2;
+    }
+
+    (document, part1, part2)
+}
+
+fn report(name: &str, size: usize, elapsed: Duration) {
+    let throughput = size as f64 / (1024.0 * 1024.0) / elapsed.as_secs_f64();
+    println!("  {name:<22} {elapsed:>10.2?} {throughput:>9.0} MiB/s");
+}
+
+fn main() {
+    let mebibytes: usize = std::env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse().ok())
+        .unwrap_or(300);
+    let (input, part1, part2) = generate(mebibytes * 1024 * 1024);
+    let size = input.len();
+    println!(
+        "trebuchet on {:.1} MiB of generated lines, best of 3",
+        size as f64 / (1024.0 * 1024.0)
+    );
+
+    let (parsed, parse) = time(|| Trebuchet::parse(&input).unwrap());
+    report("parse", size, parse);
+    let (expected1, regular1) = time(|| Trebuchet::part1(&parsed).unwrap());
+    report("part 1", size, regular1);
+    let (expected2, regular2) = time(|| Trebuchet::part2(&parsed).unwrap());
+    report("part 2", size, regular2);
+    drop(parsed);
+
+    let (actual1, bytes1) = time(|| bytes::part1(input.as_bytes()).unwrap());
+    report("bytes::part1", size, bytes1);
+    let (actual2, bytes2) = time(|| bytes::part2(input.as_bytes()).unwrap());
+    report("bytes::part2", size, bytes2);
+
+    assert_eq!(expected1, Answer::from(part1), "part 1 is off");
+    assert_eq!(expected2, Answer::from(part2), "part 2 is off");
+    assert_eq!(Answer::from(actual1), expected1, "part 1 disagrees");
+    assert_eq!(Answer::from(actual2), expected2, "part 2 disagrees");
+    println!(
+        "  speedup (with parse):  part 1 {:.1}x, part 2 {:.1}x",
+        (parse + regular1).as_secs_f64() / bytes1.as_secs_f64(),
+        (parse + regular2).as_secs_f64() / bytes2.as_secs_f64()
+    );
+}
@@ -0,0 +1,173 @@
+//! What the Elf's bag holds, and which games it could have produced.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use aoc_core::lines::Line;
+use aoc_core::ParseError;
+
+use super::{parse_round, Color, Duplicates, Game, Palette, Round};
+
+/// How many cubes of each color a bag holds. A color the bag does not name
+/// has no cubes in it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bag {
+    capacities: BTreeMap<Color, usize>,
+}
+
+impl Bag {
+    /// The bag of part one: 12 red, 13 green and 14 blue cubes.
+    pub fn puzzle() -> Self {
+        [("red", 12), ("green", 13), ("blue", 14)]
+            .into_iter()
+            .map(|(name, capacity)| (Color::new(name), capacity))
+            .collect()
+    }
+
+    /// Reads a bag written like a round, "12 red, 13 green, 14 blue", with
+    /// colors from `palette`. Naming a color twice is an error.
+    pub fn parse(text: &str, palette: &mut Palette) -> Result<Self, ParseError> {
+        let line = Line { number: 1, text };
+
+        Ok(parse_round(line, palette, Duplicates::Error)?.into())
+    }
+
+    /// Reads a bag for games read with `palette`: it may name their colors
+    /// and the puzzle's red, green and blue, so a misspelt color is an error
+    /// while the bag of part one always reads.
+    pub fn parse_for(text: &str, palette: &Palette) -> Result<Self, ParseError> {
+        let names = palette
+            .colors()
+            .map(Color::name)
+            .chain(["red", "green", "blue"]);
+
+        Bag::parse(text, &mut Palette::new(names))
+    }
+
+    /// How many `color` cubes the bag holds.
+    pub fn capacity(&self, color: &str) -> usize {
+        self.capacities.get(color).copied().unwrap_or(0)
+    }
+
+    /// Whether every round of `game` could have been drawn from this bag.
+    pub fn holds(&self, game: &Game) -> bool {
+        game.max()
+            .iter()
+            .all(|(color, count)| count <= self.capacity(color.name()))
+    }
+}
+
+impl From<Round> for Bag {
+    fn from(round: Round) -> Self {
+        Bag {
+            capacities: round.cubes,
+        }
+    }
+}
+
+impl FromIterator<(Color, usize)> for Bag {
+    fn from_iter<I: IntoIterator<Item = (Color, usize)>>(capacities: I) -> Self {
+        Bag {
+            capacities: capacities.into_iter().collect(),
+        }
+    }
+}
+
+/// Reads a bag of the puzzle's red, green and blue cubes.
+impl FromStr for Bag {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Bag::parse(text, &mut Palette::rgb())
+    }
+}
+
+/// The ids of the games that `bag` could have produced, in input order.
+pub fn possible_games(games: &[Game], bag: &Bag) -> Vec<usize> {
+    games
+        .iter()
+        .filter(|game| bag.holds(game))
+        .map(Game::id)
+        .collect()
+}
+
+mod test {
+    #[cfg(test)]
+    mod possible_games {
+        use super::super::{possible_games, Bag};
+        use crate::cube_conundrum::{lines_into_games, parse_with, Duplicates, Palette};
+
+        const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+        #[test]
+        fn test_puzzle_bag() {
+            let games = lines_into_games(EXAMPLE).unwrap();
+
+            assert_eq!(possible_games(&games, &Bag::puzzle()), vec![1, 2, 5]);
+            assert_eq!(
+                "12 red, 13 green, 14 blue".parse::<Bag>().unwrap(),
+                Bag::puzzle()
+            );
+        }
+
+        #[test]
+        fn test_other_bags() {
+            let games = lines_into_games(EXAMPLE).unwrap();
+            let possible = |bag: &str| possible_games(&games, &bag.parse().unwrap());
+
+            assert_eq!(possible("20 red, 13 green, 15 blue"), vec![1, 2, 3, 4, 5]);
+            assert_eq!(possible("4 red, 3 green, 6 blue"), vec![1, 2]);
+            assert_eq!(possible("100 red, 100 green"), Vec::<usize>::new());
+        }
+
+        #[test]
+        fn test_other_colors() {
+            let mut palette = Palette::discover();
+            let games = parse_with(
+                "Game 1: 2 yellow\nGame 2: 1 red, 1 purple\n",
+                &mut palette,
+                Duplicates::Error,
+            )
+            .unwrap();
+            let bag = Bag::parse("2 yellow, 5 red", &mut palette).unwrap();
+
+            assert_eq!(possible_games(&games, &bag), vec![1]);
+            assert_eq!(bag.capacity("purple"), 0);
+        }
+
+        #[test]
+        fn test_puzzle_colors_without_games() {
+            let mut palette = Palette::discover();
+            let games =
+                parse_with("Game 1: 2 red, 1 yellow\n", &mut palette, Duplicates::Error).unwrap();
+            let bag = Bag::parse_for("12 red, 13 green, 14 blue", &palette).unwrap();
+
+            assert_eq!(bag, Bag::puzzle());
+            assert_eq!(possible_games(&games, &bag), Vec::<usize>::new());
+        }
+
+        #[test]
+        fn test_bad_bag() {
+            let error = "12 red, 13 grean".parse::<Bag>().unwrap_err();
+            assert_eq!((error.line, error.column), (1, 12));
+            assert_eq!(error.token, "grean");
+            assert_eq!(error.expected, "red, green or blue");
+
+            let mut palette = Palette::discover();
+            parse_with("Game 1: 2 yellow, 1 red\n", &mut palette, Duplicates::Error).unwrap();
+            let error = Bag::parse_for("2 yellow, 1 purple", &palette).unwrap_err();
+            assert_eq!(error.token, "purple");
+            assert_eq!(error.expected, "red, green, blue or yellow");
+
+            let error = "12 red, lots blue".parse::<Bag>().unwrap_err();
+            assert_eq!(error.token, "lots");
+            assert_eq!(error.expected, "a cube count");
+        }
+    }
+}
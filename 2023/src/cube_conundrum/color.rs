@@ -0,0 +1,189 @@
+//! Cube colors and the palettes they are drawn from.
+//!
+//! The puzzle only has red, green and blue cubes, but variants of the game
+//! bring their own. A [`Palette`] either declares its colors up front and
+//! rejects any other, or discovers them as the input names them; either way
+//! each name is stored once and shared by every cube count that uses it.
+
+use std::borrow::Borrow;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::Arc;
+
+use super::grammar::DELIMITERS;
+
+/// A cube color. Colors compare and order by name.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Color(Arc<str>);
+
+impl Color {
+    /// A color outside of any palette, for building rounds by hand. Panics
+    /// unless [`Color::is_valid`] accepts `name`.
+    pub fn new(name: &str) -> Self {
+        assert!(Color::is_valid(name), "{name:?} cannot name a color");
+        Color(name.into())
+    }
+
+    /// Whether `name` can be written in a round and read back: it is not
+    /// empty and has no spaces, control characters, ",", ";" or ":".
+    pub fn is_valid(name: &str) -> bool {
+        !name.is_empty()
+            && !name
+                .chars()
+                .any(|char| DELIMITERS.contains(&char) || char.is_control())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Color {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The colors a game may use.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Palette {
+    colors: BTreeSet<Color>,
+    /// Whether unknown names become new colors rather than errors.
+    open: bool,
+}
+
+impl Palette {
+    /// A palette of exactly `names`. Panics on a name that cannot name a
+    /// color.
+    pub fn new<'a>(names: impl IntoIterator<Item = &'a str>) -> Self {
+        Palette {
+            colors: names.into_iter().map(Color::new).collect(),
+            open: false,
+        }
+    }
+
+    /// The red, green and blue of the puzzle.
+    pub fn rgb() -> Self {
+        Palette::new(["red", "green", "blue"])
+    }
+
+    /// An empty palette that takes in every color it is asked for.
+    pub fn discover() -> Self {
+        Palette {
+            colors: BTreeSet::new(),
+            open: true,
+        }
+    }
+
+    /// The color called `name`, added first if the palette discovers its
+    /// colors. `None` for a name a declared palette does not have, or one
+    /// that cannot name a color at all.
+    pub fn color(&mut self, name: &str) -> Option<Color> {
+        if let Some(color) = self.colors.get(name) {
+            return Some(color.clone());
+        }
+        if !self.open || !Color::is_valid(name) {
+            return None;
+        }
+
+        let color = Color::new(name);
+        self.colors.insert(color.clone());
+        Some(color)
+    }
+
+    /// Every color so far, by name.
+    pub fn colors(&self) -> impl Iterator<Item = &Color> {
+        self.colors.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// What a color name should have been, for error messages: "red, green
+    /// or blue" for the puzzle's palette.
+    pub(super) fn expected(&self) -> String {
+        if self.open {
+            return "a color name".to_string();
+        }
+
+        // The puzzle's colors read better in their usual order, so they come
+        // first and the rest follow by name.
+        let rgb = ["red", "green", "blue"];
+        let mut names: Vec<&str> = self.colors.iter().map(Color::name).collect();
+        names.sort_by_key(|name| rgb.iter().position(|rgb| rgb == name).unwrap_or(rgb.len()));
+
+        match names.split_last() {
+            None => "no color at all".to_string(),
+            Some((last, [])) => last.to_string(),
+            Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+        }
+    }
+}
+
+mod test {
+    #[cfg(test)]
+    mod palette {
+        use super::super::{Color, Palette};
+
+        #[test]
+        fn test_declared() {
+            let mut palette = Palette::rgb();
+
+            assert_eq!(palette.color("green"), Some(Color::new("green")));
+            assert_eq!(palette.color("purple"), None);
+            assert_eq!(palette.len(), 3);
+            assert_eq!(palette.expected(), "red, green or blue");
+        }
+
+        #[test]
+        fn test_discovered() {
+            let mut palette = Palette::discover();
+            let first = palette.color("yellow").unwrap();
+            let second = palette.color("yellow").unwrap();
+            palette.color("purple");
+
+            assert!(std::ptr::eq(first.name(), second.name()));
+            assert_eq!(
+                palette.colors().map(Color::name).collect::<Vec<_>>(),
+                ["purple", "yellow"]
+            );
+        }
+
+        #[test]
+        fn test_names() {
+            assert!(Color::is_valid("sky-blue"));
+            assert!(Color::is_valid("vermelho"));
+            for name in ["", "dark blue", "a,b", "a;b", "a:b", "a\tb"] {
+                assert!(!Color::is_valid(name), "{name:?}");
+                assert_eq!(Palette::discover().color(name), None, "{name:?}");
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "cannot name a color")]
+        fn test_invalid_name() {
+            let _ = Color::new("dark blue");
+        }
+
+        #[test]
+        fn test_expected() {
+            assert_eq!(Palette::new(["yellow"]).expected(), "yellow");
+            assert_eq!(
+                Palette::new(["yellow", "purple", "cyan"]).expected(),
+                "cyan, purple or yellow"
+            );
+            assert_eq!(Palette::discover().expected(), "a color name");
+        }
+    }
+}
@@ -0,0 +1,362 @@
+//! The text form of games and rounds, read strictly and written canonically.
+//!
+//! ```text
+//! game  = "Game " id ": " round *("; " round)
+//! round = cubes *(", " cubes)
+//! cubes = count " " color
+//! id    = 1*DIGIT
+//! count = 1*DIGIT
+//! color = 1*(any character but space, ",", ";", ":" and control characters)
+//! ```
+//!
+//! Separators are exactly as shown, so a doubled or missing space is an error
+//! pointing at where it went wrong. [`Display`] writes the same form, with
+//! each round's colors by name, and reading that text back gives the same
+//! game: [`Color::new`] and [`Game::new`] refuse names and games the grammar
+//! has no text for. A [`Round`] built empty by hand writes as nothing, which
+//! does not read back.
+//!
+//! [`Display`]: fmt::Display
+//! [`Color::new`]: super::Color::new
+
+use std::collections::btree_map::Entry;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_core::lines::Line;
+use aoc_core::ParseError;
+
+use super::{Duplicates, Game, Palette, Round};
+
+/// Characters that end a color name or a malformed token.
+pub(super) const DELIMITERS: [char; 4] = [' ', ',', ';', ':'];
+
+/// Reads `line` as a game.
+pub(super) fn parse_game(
+    line: Line<'_>,
+    palette: &mut Palette,
+    duplicates: Duplicates,
+) -> Result<Game, ParseError> {
+    let mut cursor = Cursor::new(line);
+
+    cursor.expect("Game ", "\"Game <id>\"")?;
+    let (id, _) = cursor.number("a game id")?;
+    cursor.expect(": ", "\": \" after the game id")?;
+    let mut rounds = vec![cursor.round(palette, duplicates)?];
+    while cursor.eat("; ") {
+        rounds.push(cursor.round(palette, duplicates)?);
+    }
+    cursor.finish("\", \", \"; \" or the end of the line")?;
+
+    Ok(Game { id, rounds })
+}
+
+/// Reads all of `line` as a single round, like "3 blue, 4 red".
+pub(super) fn parse_round(
+    line: Line<'_>,
+    palette: &mut Palette,
+    duplicates: Duplicates,
+) -> Result<Round, ParseError> {
+    let mut cursor = Cursor::new(line);
+    let round = cursor.round(palette, duplicates)?;
+    cursor.finish("\", \" or the end of the line")?;
+
+    Ok(round)
+}
+
+/// Reads a round of any colors; naming a color twice is an error.
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let line = Line { number: 1, text };
+
+        parse_round(line, &mut Palette::discover(), Duplicates::Error)
+    }
+}
+
+/// Reads a game of any colors; naming a color twice in a round is an error.
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let line = Line { number: 1, text };
+
+        parse_game(line, &mut Palette::discover(), Duplicates::Error)
+    }
+}
+
+impl fmt::Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (color, count)) in self.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{count} {color}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}: ", self.id)?;
+        for (index, round) in self.rounds.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{round}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The unread rest of a line.
+struct Cursor<'a> {
+    line: Line<'a>,
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(line: Line<'a>) -> Self {
+        Cursor {
+            line,
+            rest: line.text,
+        }
+    }
+
+    /// An error at the token the cursor is on: a whole word, or a single
+    /// separator, or nothing at the end of the line.
+    fn error(&self, expected: &str) -> ParseError {
+        let length = match self.rest.find(DELIMITERS) {
+            Some(0) => self.rest.chars().next().map_or(0, char::len_utf8),
+            Some(end) => end,
+            None => self.rest.len(),
+        };
+
+        self.line.error(&self.rest[..length], expected)
+    }
+
+    /// Moves past `text` if the rest starts with it.
+    fn eat(&mut self, text: &str) -> bool {
+        match self.rest.strip_prefix(text) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn expect(&mut self, text: &str, expected: &str) -> Result<(), ParseError> {
+        if self.eat(text) {
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    fn finish(&self, expected: &str) -> Result<(), ParseError> {
+        if self.rest.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    /// Moves past the word that comes next, which may be empty.
+    fn word(&mut self) -> &'a str {
+        let end = self.rest.find(DELIMITERS).unwrap_or(self.rest.len());
+        let (word, rest) = self.rest.split_at(end);
+        self.rest = rest;
+
+        word
+    }
+
+    /// A word made of ASCII digits only, with its text.
+    fn number(&mut self, expected: &str) -> Result<(usize, &'a str), ParseError> {
+        let rest = self.rest;
+        let digits = self.word();
+
+        match digits.parse() {
+            // `parse` alone would also take a leading '+'.
+            Ok(number) if digits.bytes().all(|byte| byte.is_ascii_digit()) => Ok((number, digits)),
+            _ => {
+                self.rest = rest;
+                Err(self.error(expected))
+            }
+        }
+    }
+
+    fn round(
+        &mut self,
+        palette: &mut Palette,
+        duplicates: Duplicates,
+    ) -> Result<Round, ParseError> {
+        let mut round = Round::default();
+
+        loop {
+            let (count, count_text) = self.number("a cube count")?;
+            self.expect(" ", "a space after the cube count")?;
+            if self.rest.starts_with(DELIMITERS) || self.rest.is_empty() {
+                return Err(self.error(&palette.expected()));
+            }
+            let color_text = self.word();
+            let Some(color) = palette.color(color_text) else {
+                return Err(self.line.error(color_text, palette.expected()));
+            };
+
+            match round.cubes.entry(color) {
+                Entry::Vacant(entry) => {
+                    entry.insert(count);
+                }
+                Entry::Occupied(mut entry) => {
+                    let kept = entry.get_mut();
+                    *kept = match duplicates {
+                        Duplicates::Error => {
+                            let error = "a color not already in the round";
+                            return Err(self.line.error(color_text, error));
+                        }
+                        Duplicates::Sum => kept
+                            .checked_add(count)
+                            .ok_or_else(|| self.line.error(count_text, "a smaller cube count"))?,
+                        Duplicates::Max => count.max(*kept),
+                    };
+                }
+            }
+
+            if !self.eat(", ") {
+                return Ok(round);
+            }
+        }
+    }
+}
+
+mod test {
+    #[cfg(test)]
+    mod strict {
+        use super::super::super::{Game, Round};
+        use aoc_core::ParseError;
+
+        fn error(text: &str) -> ParseError {
+            text.parse::<Game>().unwrap_err()
+        }
+
+        #[test]
+        fn test_canonical_text() {
+            let game: Game = "Game 12: 3 blue, 4 red; 2 green".parse().unwrap();
+
+            assert_eq!(game.id(), 12);
+            assert_eq!(game.rounds()[0].count("red"), 4);
+            assert_eq!(game.to_string(), "Game 12: 3 blue, 4 red; 2 green");
+            assert_eq!(
+                "4 red, 3 blue".parse::<Round>().unwrap().to_string(),
+                "3 blue, 4 red"
+            );
+        }
+
+        #[test]
+        fn test_spacing() {
+            let doubled = error("Game 1:  3 red");
+            assert_eq!((doubled.column, doubled.token.as_str()), (9, " "));
+            assert_eq!(doubled.expected, "a cube count");
+
+            let doubled = error("Game 1: 3  red");
+            assert_eq!((doubled.column, doubled.token.as_str()), (11, " "));
+
+            let missing = error("Game 1: 3 red,4 blue");
+            assert_eq!((missing.column, missing.token.as_str()), (14, ","));
+            assert_eq!(missing.expected, "\", \", \"; \" or the end of the line");
+
+            let trailing = error("Game 1: 3 red ");
+            assert_eq!((trailing.column, trailing.token.as_str()), (14, " "));
+        }
+
+        #[test]
+        fn test_labels_and_numbers() {
+            assert_eq!(error("Gamer 1: 3 red").expected, "\"Game <id>\"");
+            assert_eq!(error("Game +1: 3 red").token, "+1");
+            assert_eq!(error("Game 1: 3x red").token, "3x");
+            assert_eq!(error("Game 1: 3 red;").token, ";");
+            assert_eq!(error("Game 1: ").expected, "a cube count");
+            assert_eq!(error("Game 1: 3 red, 1 red").column, 18);
+        }
+    }
+
+    #[cfg(test)]
+    mod round_trip {
+        use super::super::super::{Color, Game, Palette, Round};
+        use crate::cube_conundrum::{parse_with, Duplicates};
+        use aoc_core::rng::Rng;
+
+        /// Names at the edges of what the grammar takes.
+        const ODD_NAMES: [&str; 6] = ["0", "+1", "sky-blue", "ünïcödé", "a.b", "\"x\""];
+
+        fn name(rng: &mut Rng) -> String {
+            if rng.chance(0.2) {
+                return ODD_NAMES[rng.below(ODD_NAMES.len())].to_string();
+            }
+
+            (0..rng.below(8) + 1)
+                .map(|_| char::from(b'a' + rng.below(26) as u8))
+                .collect()
+        }
+
+        fn count(rng: &mut Rng) -> usize {
+            match rng.below(6) {
+                0 => rng.next_u64() as usize,
+                1 => [0, usize::MAX][rng.below(2)],
+                _ => rng.below(30),
+            }
+        }
+
+        fn game(rng: &mut Rng, colors: &[String]) -> Game {
+            let rounds = (0..rng.below(5) + 1)
+                .map(|_| {
+                    (0..rng.below(colors.len()) + 1)
+                        .map(|_| (Color::new(&colors[rng.below(colors.len())]), count(rng)))
+                        .collect::<Round>()
+                })
+                .collect();
+
+            Game::new(count(rng), rounds)
+        }
+
+        #[test]
+        fn test_display_parses_back() {
+            let mut rng = Rng::new(25);
+
+            for _ in 0..200 {
+                let colors: Vec<String> = (0..rng.below(6) + 1).map(|_| name(&mut rng)).collect();
+                let games: Vec<Game> = (0..rng.below(10) + 1)
+                    .map(|_| game(&mut rng, &colors))
+                    .collect();
+
+                for game in &games {
+                    assert_eq!(game.to_string().parse::<Game>().as_ref(), Ok(game));
+                    for round in game.rounds() {
+                        assert_eq!(round.to_string().parse::<Round>().as_ref(), Ok(round));
+                    }
+                }
+
+                let document: String = games.iter().map(|game| format!("{game}\n")).collect();
+                let parsed = parse_with(&document, &mut Palette::discover(), Duplicates::Error);
+                assert_eq!(parsed, Ok(games));
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "rounds")]
+        fn test_game_without_rounds() {
+            let _ = Game::new(1, Vec::new());
+        }
+
+        #[test]
+        #[should_panic(expected = "rounds")]
+        fn test_game_with_empty_round() {
+            let _ = Game::new(1, vec![Round::default()]);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! The smallest bag each game could have been played with, and its power.
+//!
+//! Part two multiplies together the most cubes of each color a game shows.
+//! A color the game never shows makes that product 0, which is what the
+//! puzzle asks for but easy to mistake for a bug in a large input, so the
+//! treatment of such colors is a choice here and every game's share of the
+//! answer can be listed on its own.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use aoc_core::{Natural, Overflow};
+
+use super::{Color, Game, Palette};
+
+/// What a color a game never shows counts for in its power.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Absent {
+    /// The bag needs none of it, so the power is 0, as in the puzzle.
+    #[default]
+    Zero,
+    /// Leave the color out of the product.
+    One,
+    /// Refuse to compute the power, naming the missing colors.
+    Report,
+}
+
+/// Why a game has no power.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PowerError {
+    /// The game never shows these colors, under [`Absent::Report`].
+    Absent(Vec<Color>),
+    Overflow(Overflow),
+}
+
+impl fmt::Display for PowerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PowerError::Absent(colors) => {
+                let names: Vec<&str> = colors.iter().map(Color::name).collect();
+                write!(f, "no {} cubes shown", names.join(" or "))
+            }
+            PowerError::Overflow(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for PowerError {}
+
+impl From<Overflow> for PowerError {
+    fn from(error: Overflow) -> Self {
+        PowerError::Overflow(error)
+    }
+}
+
+/// The fewest cubes of every color of a palette that make a game possible.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MinimumBag {
+    cubes: BTreeMap<Color, usize>,
+    /// The colors the game shows, even if only as "0 blue".
+    shown: BTreeSet<Color>,
+    absent: Absent,
+}
+
+impl MinimumBag {
+    /// The minimum bag of `game` over the colors of `palette`, with colors
+    /// the game never shows handled as `absent` says.
+    pub fn new(game: &Game, palette: &Palette, absent: Absent) -> Self {
+        let max = game.max();
+
+        MinimumBag {
+            cubes: palette
+                .colors()
+                .map(|color| (color.clone(), max.count(color.name())))
+                .collect(),
+            shown: max.cubes.into_keys().collect(),
+            absent,
+        }
+    }
+
+    /// How many `color` cubes the bag needs.
+    pub fn count(&self, color: &str) -> usize {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+
+    /// The palette colors the game never shows a cube of, by name.
+    pub fn missing(&self) -> Vec<Color> {
+        self.cubes
+            .keys()
+            .filter(|color| !self.shown.contains(*color))
+            .cloned()
+            .collect()
+    }
+
+    /// The product of the cube counts.
+    pub fn power(&self) -> Result<Natural, PowerError> {
+        let counts = self.cubes.values().copied();
+
+        Ok(match self.absent {
+            Absent::Zero => Natural::product(counts)?,
+            Absent::One => Natural::product(
+                self.cubes
+                    .iter()
+                    .filter(|(color, _)| self.shown.contains(*color))
+                    .map(|(_, &count)| count),
+            )?,
+            Absent::Report => match self.missing() {
+                missing if missing.is_empty() => Natural::product(counts)?,
+                missing => return Err(PowerError::Absent(missing)),
+            },
+        })
+    }
+}
+
+/// Written like a round, colors by name: "6 blue, 13 green, 20 red".
+impl fmt::Display for MinimumBag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes: Vec<String> = self
+            .cubes
+            .iter()
+            .map(|(color, count)| format!("{count} {color}"))
+            .collect();
+
+        f.write_str(&cubes.join(", "))
+    }
+}
+
+/// One game's share of part two.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PowerReport {
+    pub id: usize,
+    pub minimum: MinimumBag,
+    pub power: Result<Natural, PowerError>,
+}
+
+/// Reads "Game 3: 6 blue, 13 green, 20 red -> power 1560", or the reason in
+/// place of the power.
+impl fmt::Display for PowerReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}: {} -> ", self.id, self.minimum)?;
+        match &self.power {
+            Ok(power) => write!(f, "power {power}"),
+            Err(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+/// The minimum bag and power of every game, in input order.
+pub fn power_report(games: &[Game], palette: &Palette, absent: Absent) -> Vec<PowerReport> {
+    games
+        .iter()
+        .map(|game| {
+            let minimum = MinimumBag::new(game, palette, absent);
+            PowerReport {
+                id: game.id(),
+                power: minimum.power(),
+                minimum,
+            }
+        })
+        .collect()
+}
+
+mod test {
+    #[cfg(test)]
+    mod minimum_bag {
+        use super::super::{power_report, Absent, MinimumBag, PowerError};
+        use crate::cube_conundrum::{lines_into_games, Color, Palette};
+        use aoc_core::Natural;
+
+        const GAMES: &str = "\
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 6: 2 red, 3 green; 4 red
+Game 7: 2 red, 0 blue; 3 green
+";
+
+        #[test]
+        fn test_counts() {
+            let games = lines_into_games(GAMES).unwrap();
+            let minimum = MinimumBag::new(&games[0], &Palette::rgb(), Absent::Zero);
+
+            assert_eq!(minimum.count("red"), 20);
+            assert_eq!(minimum.count("green"), 13);
+            assert_eq!(minimum.count("blue"), 6);
+            assert_eq!(minimum.to_string(), "6 blue, 13 green, 20 red");
+            assert_eq!(minimum.power(), Ok(Natural::from(1560u64)));
+        }
+
+        #[test]
+        fn test_absent_policies() {
+            let games = lines_into_games(GAMES).unwrap();
+            let power = |absent| MinimumBag::new(&games[1], &Palette::rgb(), absent).power();
+
+            assert_eq!(power(Absent::Zero), Ok(Natural::ZERO));
+            assert_eq!(power(Absent::One), Ok(Natural::from(12u64)));
+            assert_eq!(
+                power(Absent::Report),
+                Err(PowerError::Absent(vec![Color::new("blue")]))
+            );
+        }
+
+        #[test]
+        fn test_zero_is_shown() {
+            let games = lines_into_games(GAMES).unwrap();
+            let minimum = |absent| MinimumBag::new(&games[2], &Palette::rgb(), absent);
+
+            assert_eq!(minimum(Absent::Report).missing(), Vec::<Color>::new());
+            assert_eq!(minimum(Absent::Report).power(), Ok(Natural::ZERO));
+            assert_eq!(minimum(Absent::One).power(), Ok(Natural::ZERO));
+        }
+
+        #[test]
+        fn test_report() {
+            let games = lines_into_games(GAMES).unwrap();
+            let lines: Vec<String> = power_report(&games, &Palette::rgb(), Absent::Report)
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+
+            assert_eq!(
+                lines,
+                [
+                    "Game 3: 6 blue, 13 green, 20 red -> power 1560",
+                    "Game 6: 0 blue, 3 green, 4 red -> no blue cubes shown",
+                    "Game 7: 0 blue, 3 green, 2 red -> power 0",
+                ]
+            );
+        }
+    }
+}
@@ -0,0 +1,575 @@
+/*
+https://adventofcode.com/2023/day/2
+
+You're launched high into the atmosphere! The apex of your trajectory just barely
+reaches the surface of a large island floating in the sky. You gently land in a fluffy
+pile of leaves. It's quite cold, but you don't see much snow. An Elf runs over to greet you.
+
+The Elf explains that you've arrived at Snow Island and apologizes for the lack of snow.
+He'll be happy to explain the situation, but it's a bit of a walk, so you have some time.
+They don't get many visitors up here; would you like to play a game in the meantime?
+
+As you walk, the Elf shows you a small bag and some cubes which are either red,
+green, or blue. Each time you play this game, he will hide a secret number of cubes of
+each color in the bag, and your goal is to figure out information about the number of cubes.
+
+To get information, once a bag has been loaded with cubes, the Elf will reach into the bag,
+grab a handful of random cubes, show them to you, and then put them back in the bag.
+He'll do this a few times per game.
+
+You play several games and record the information from each game (your puzzle input).
+Each game is listed with its ID number (like the 11 in Game 11: ...) followed by a
+semicolon-separated list of subsets of cubes that were revealed from
+the bag (like 3 red, 5 green, 4 blue).
+
+For example, the record of a few games might look like this:
+```
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+```
+
+In game 1, three sets of cubes are revealed from the bag (and then put back again).
+The first set is 3 blue cubes and 4 red cubes; the second set is 1 red cube, 2 green cubes,
+and 6 blue cubes; the third set is only 2 green cubes.
+
+The Elf would first like to know which games would have been possible if the bag contained
+only 12 red cubes, 13 green cubes, and 14 blue cubes?
+
+In the example above, games 1, 2, and 5 would have been possible if the bag had been
+loaded with that configuration. However, game 3 would have been impossible because
+at one point the Elf showed you 20 red cubes at once; similarly, game 4 would also
+have been impossible because the Elf showed you 15 blue cubes at once.
+If you add up the IDs of the games that would have been possible, you get 8.
+
+Determine which games would have been possible if the bag had been loaded with
+only 12 red cubes, 13 green cubes, and 14 blue cubes.
+What is the sum of the IDs of those games?
+*/
+
+/*
+https://adventofcode.com/2023/day/2
+
+The Elf says they've stopped producing snow because they aren't getting any water!
+He isn't sure why the water stopped; however, he can show you how to get to the water source
+to check it out for yourself. It's just up ahead!
+
+As you continue your walk, the Elf poses a second question:
+in each game you played, what is the fewest number of cubes of each color that could have been
+in the bag to make the game possible?
+
+Again consider the example games from earlier:
+```
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+```
+
+In game 1, the game could have been played with as few as 4 red, 2 green, and 6 blue cubes.
+If any color had even one fewer cube, the game would have been impossible.
+- Game 2 could have been played with a minimum of 1 red, 3 green, and 4 blue cubes.
+- Game 3 must have been played with at least 20 red, 13 green, and 6 blue cubes.
+- Game 4 required at least 14 red, 3 green, and 15 blue cubes.
+- Game 5 needed no fewer than 6 red, 3 green, and 2 blue cubes in the bag.
+
+The power of a set of cubes is equal to the numbers of red, green, and blue cubes multiplied together.
+The power of the minimum set of cubes in game 1 is 48. In games 2-5 it was 12, 1560, 630,
+and 36, respectively. Adding up these five powers produces the sum 2286.
+
+For each game, find the minimum set of cubes that must have been present.
+What is the sum of the power of these sets?
+*/
+
+pub mod bag;
+pub mod color;
+mod grammar;
+pub mod minimum;
+
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use std::sync::OnceLock;
+
+use aoc_core::lines::{lines, BlankLines, Lines};
+use aoc_core::stream::{self, ReadError};
+use aoc_core::{Answer, Natural, Overflow, ParseError, Part, Solution, SolveError};
+
+pub use bag::{possible_games, Bag};
+pub use color::{Color, Palette};
+use grammar::{parse_game, parse_round};
+pub use minimum::{power_report, Absent, MinimumBag, PowerError, PowerReport};
+
+pub struct CubeConundrum;
+
+impl Solution for CubeConundrum {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines_into_games(input)
+    }
+
+    fn part1(games: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part_sum(games, Part::One)?.into())
+    }
+
+    fn part2(games: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(part_sum(games, Part::Two)?.into())
+    }
+
+    #[cfg(feature = "parallel")]
+    fn solve(input: &str, part: Part, threads: usize) -> Result<Answer, SolveError> {
+        let sum = aoc_core::parallel::sum_chunks(
+            input,
+            threads,
+            |chunk| {
+                parse_games(
+                    chunk.lines(BlankLines::Error),
+                    &mut rgb().clone(),
+                    Duplicates::Error,
+                )
+            },
+            |games| Ok(part_sum(games, part)?),
+        )?;
+
+        Ok(sum.into())
+    }
+
+    fn solve_reader(reader: &mut dyn BufRead, parts: &[Part]) -> Result<Vec<Answer>, ReadError> {
+        let mut palette = rgb().clone();
+        let sums = stream::sum_lines(reader, parts, BlankLines::Error, |line, parts| {
+            let game = parse_game(line, &mut palette, Duplicates::Error)?;
+
+            Ok(parts
+                .iter()
+                .map(|&part| game_answer(&game, part))
+                .collect::<Result<_, _>>()?)
+        })?;
+
+        Ok(sums.into_iter().map(Answer::from).collect())
+    }
+}
+
+fn part_sum(games: &[Game], part: Part) -> Result<Natural, Overflow> {
+    games.iter().try_fold(Natural::ZERO, |sum, game| {
+        sum.checked_add(game_answer(game, part)?)
+    })
+}
+
+/// What `game` adds to the answer of `part`.
+fn game_answer(game: &Game, part: Part) -> Result<Natural, Overflow> {
+    match part {
+        Part::One => Ok(possible_id(game).into()),
+        Part::Two => game.power(rgb()),
+    }
+}
+
+/// The puzzle's palette.
+fn rgb() -> &'static Palette {
+    static RGB: OnceLock<Palette> = OnceLock::new();
+
+    RGB.get_or_init(Palette::rgb)
+}
+
+/// The id of `game` if it could have been played with the bag of part one,
+/// or else 0.
+fn possible_id(game: &Game) -> usize {
+    static PUZZLE: OnceLock<Bag> = OnceLock::new();
+
+    if PUZZLE.get_or_init(Bag::puzzle).holds(game) {
+        game.id
+    } else {
+        0
+    }
+}
+
+/// The cubes shown in one handful, counted by color.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Round {
+    cubes: BTreeMap<Color, usize>,
+}
+
+impl Round {
+    /// How many `color` cubes were shown; 0 for a color the round lacks.
+    pub fn count(&self, color: &str) -> usize {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+
+    /// Every color in the round with its count, by color name.
+    pub fn iter(&self) -> impl Iterator<Item = (&Color, usize)> {
+        self.cubes.iter().map(|(color, &count)| (color, count))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cubes.is_empty()
+    }
+
+    /// Merges `other` into this round, keeping whichever count of each color
+    /// `keep` picks.
+    fn merge(&mut self, other: &Round, keep: fn(usize, usize) -> usize) {
+        for (color, &count) in &other.cubes {
+            self.cubes
+                .entry(color.clone())
+                .and_modify(|kept| *kept = keep(*kept, count))
+                .or_insert(count);
+        }
+    }
+}
+
+impl FromIterator<(Color, usize)> for Round {
+    fn from_iter<I: IntoIterator<Item = (Color, usize)>>(cubes: I) -> Self {
+        Round {
+            cubes: cubes.into_iter().collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    id: usize,
+    rounds: Vec<Round>,
+}
+
+impl Game {
+    /// Panics unless the game has at least one round and every round shows
+    /// at least one color, as the text form requires.
+    pub fn new(id: usize, rounds: Vec<Round>) -> Self {
+        assert!(
+            !rounds.is_empty() && !rounds.iter().any(Round::is_empty),
+            "a game needs rounds that each show a color"
+        );
+        Game { id, rounds }
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    /// The most cubes of each color shown at once, over every color the
+    /// game shows.
+    pub fn max(&self) -> Round {
+        self.fold(usize::max)
+    }
+
+    /// The fewest cubes of each color shown at once, over the rounds that
+    /// show that color at all.
+    pub fn min(&self) -> Round {
+        self.fold(usize::min)
+    }
+
+    /// The product of the most cubes shown of each color in `palette`: the
+    /// power of the smallest bag that makes the game possible. A color the
+    /// game never shows makes it 0; see [`MinimumBag`] for other choices.
+    pub fn power(&self, palette: &Palette) -> Result<Natural, Overflow> {
+        let max = self.max();
+
+        Natural::product(palette.colors().map(|color| max.count(color.name())))
+    }
+
+    fn fold(&self, keep: fn(usize, usize) -> usize) -> Round {
+        let mut folded = Round::default();
+        for round in &self.rounds {
+            folded.merge(round, keep);
+        }
+
+        folded
+    }
+}
+
+/// What to do with a color named twice in one round, as in "3 red, 4 red".
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Duplicates {
+    /// Reject the round, pointing at the second mention.
+    #[default]
+    Error,
+    /// Count the cubes of every mention.
+    Sum,
+    /// Keep the largest count.
+    Max,
+}
+
+fn lines_into_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    parse_games(
+        lines(input, BlankLines::Error),
+        &mut rgb().clone(),
+        Duplicates::Error,
+    )
+}
+
+/// Parses a whole record of games, taking their colors from `palette`: a
+/// declared palette rejects any other color, a discovering one learns them.
+/// A color named twice in a round is handled as `duplicates` says.
+pub fn parse_with(
+    input: &str,
+    palette: &mut Palette,
+    duplicates: Duplicates,
+) -> Result<Vec<Game>, ParseError> {
+    parse_games(lines(input, BlankLines::Error), palette, duplicates)
+}
+
+fn parse_games(
+    lines: Lines<'_>,
+    palette: &mut Palette,
+    duplicates: Duplicates,
+) -> Result<Vec<Game>, ParseError> {
+    lines
+        .map(|line| parse_game(line?, palette, duplicates))
+        .collect()
+}
+
+mod test {
+    #[cfg(test)]
+    mod lines_into_games {
+        use super::super::{lines_into_games, Color, Game, Round};
+        use aoc_core::ParseError;
+
+        pub(super) fn round(cubes: &[(&str, usize)]) -> Round {
+            cubes
+                .iter()
+                .map(|&(name, count)| (Color::new(name), count))
+                .collect()
+        }
+
+        fn error_at(line: &str) -> ParseError {
+            lines_into_games(&format!("Game 1: 1 red\n{line}\n")).unwrap_err()
+        }
+
+        #[test]
+        fn test_single_game() {
+            let games = lines_into_games("Game 7: 3 blue, 4 red; 2 green\r\n").unwrap();
+
+            assert_eq!(
+                games,
+                vec![Game::new(
+                    7,
+                    vec![round(&[("red", 4), ("blue", 3)]), round(&[("green", 2)])],
+                )]
+            );
+        }
+
+        #[test]
+        fn test_blank_line() {
+            let error = error_at("");
+
+            assert_eq!((error.line, error.column), (2, 1));
+            assert_eq!(error.expected, "a non-blank line");
+        }
+
+        #[test]
+        fn test_bad_game_id() {
+            let error = error_at("Game x1: 3 blue");
+
+            assert_eq!((error.line, error.column), (2, 6));
+            assert_eq!(error.token, "x1");
+            assert_eq!(error.expected, "a game id");
+        }
+
+        #[test]
+        fn test_missing_colon() {
+            let error = error_at("Game 2 3 blue");
+
+            assert_eq!((error.line, error.column), (2, 7));
+            assert_eq!(error.token, " ");
+            assert_eq!(error.expected, "\": \" after the game id");
+        }
+
+        #[test]
+        fn test_unknown_color() {
+            let error = error_at("Game 2: 3 blue; 4 purple");
+
+            assert_eq!((error.line, error.column), (2, 19));
+            assert_eq!(error.token, "purple");
+            assert_eq!(error.expected, "red, green or blue");
+        }
+
+        #[test]
+        fn test_repeated_color() {
+            let error = error_at("Game 2: 1 red, 1 red");
+
+            assert_eq!((error.line, error.column), (2, 18));
+            assert_eq!(error.token, "red");
+        }
+
+        #[test]
+        fn test_bad_count() {
+            let error = error_at("Game 2: 3 blue, many red");
+
+            assert_eq!((error.line, error.column), (2, 17));
+            assert_eq!(error.token, "many");
+        }
+    }
+
+    #[cfg(test)]
+    mod colors {
+        use super::super::{parse_with, Color, Duplicates, Palette};
+        use super::lines_into_games::round;
+        use aoc_core::Natural;
+
+        const GAMES: &str = "\
+Game 1: 3 yellow, 4 purple; 1 red, 2 yellow
+Game 2: 5 purple; 2 teal, 1 purple
+";
+
+        #[test]
+        fn test_discovered_colors() {
+            let mut palette = Palette::discover();
+            let games = parse_with(GAMES, &mut palette, Duplicates::Error).unwrap();
+
+            assert_eq!(
+                palette.colors().map(Color::name).collect::<Vec<_>>(),
+                ["purple", "red", "teal", "yellow"]
+            );
+            assert_eq!(
+                games[0].max(),
+                round(&[("yellow", 3), ("purple", 4), ("red", 1)])
+            );
+            assert_eq!(
+                games[0].min(),
+                round(&[("yellow", 2), ("purple", 4), ("red", 1)])
+            );
+            assert_eq!(games[0].power(&palette), Ok(Natural::ZERO));
+            assert_eq!(
+                games[0].power(&Palette::new(["yellow", "purple", "red"])),
+                Ok(Natural::from(12u64))
+            );
+            assert_eq!(
+                games[1].power(&Palette::new(["purple", "teal"])),
+                Ok(Natural::from(10u64))
+            );
+        }
+
+        #[test]
+        fn test_declared_colors() {
+            let mut palette = Palette::new(["yellow", "purple", "red"]);
+            let error = parse_with(GAMES, &mut palette, Duplicates::Error).unwrap_err();
+
+            assert_eq!((error.line, error.column), (2, 21));
+            assert_eq!(error.token, "teal");
+            assert_eq!(error.expected, "red, purple or yellow");
+            assert_eq!(palette.len(), 3);
+        }
+
+        #[test]
+        fn test_color_names() {
+            let mut palette = Palette::discover();
+            let error =
+                parse_with("Game 1: 2 , 3 red", &mut palette, Duplicates::Error).unwrap_err();
+
+            assert_eq!(error.token, ",");
+            assert_eq!(error.expected, "a color name");
+            assert!(palette.is_empty());
+
+            let error =
+                parse_with("Game 1: 2 sky blue", &mut palette, Duplicates::Error).unwrap_err();
+
+            assert_eq!((error.line, error.column), (1, 14));
+            assert_eq!(error.token, " ");
+        }
+    }
+
+    #[cfg(test)]
+    mod duplicates {
+        use super::super::{parse_with, Duplicates, Game, Palette};
+        use super::lines_into_games::round;
+        use aoc_core::ParseError;
+
+        const GAME: &str = "Game 1: 3 red, 2 blue, 4 red; 1 green\n";
+
+        fn parse(input: &str, duplicates: Duplicates) -> Result<Vec<Game>, ParseError> {
+            parse_with(input, &mut Palette::rgb(), duplicates)
+        }
+
+        #[test]
+        fn test_error_by_default() {
+            let error = parse(GAME, Duplicates::default()).unwrap_err();
+
+            assert_eq!((error.line, error.column), (1, 26));
+            assert_eq!(error.token, "red");
+            assert_eq!(error.expected, "a color not already in the round");
+        }
+
+        #[test]
+        fn test_sum() {
+            let games = parse(GAME, Duplicates::Sum).unwrap();
+
+            assert_eq!(games[0].rounds()[0], round(&[("red", 7), ("blue", 2)]));
+            assert_eq!(games[0].rounds()[1], round(&[("green", 1)]));
+        }
+
+        #[test]
+        fn test_sum_overflow() {
+            let input = format!("Game 1: {} red, 1 red\n", usize::MAX);
+            let error = parse(&input, Duplicates::Sum).unwrap_err();
+
+            assert_eq!(error.token, "1");
+            assert_eq!(error.expected, "a smaller cube count");
+        }
+
+        #[test]
+        fn test_max() {
+            let games = parse(GAME, Duplicates::Max).unwrap();
+
+            assert_eq!(games[0].rounds()[0], round(&[("red", 4), ("blue", 2)]));
+        }
+
+        #[test]
+        fn test_across_rounds() {
+            let input = "Game 1: 3 red; 4 red\n";
+
+            assert!(parse(input, Duplicates::Error).is_ok());
+        }
+    }
+
+    #[cfg(test)]
+    mod overflow {
+        use super::super::CubeConundrum;
+        use aoc_core::{Answer, Part, Solution, SolveError};
+
+        /// Each game's power is 2^64, one past `u64::MAX`.
+        const HUGE_POWER: &str = "Game 1: 4294967296 red, 2 green; 2147483648 blue\n";
+        /// The ids add up to 2^64.
+        const HUGE_IDS: &str = "Game 18446744073709551615: 1 red\nGame 1: 1 blue\n";
+
+        fn answer(input: &str, part: Part) -> Result<Answer, SolveError> {
+            CubeConundrum::part(&CubeConundrum::parse(input).unwrap(), part)
+        }
+
+        #[cfg(not(feature = "bigint"))]
+        #[test]
+        fn test_overflow_is_an_error() {
+            assert!(matches!(
+                answer(HUGE_POWER, Part::Two),
+                Err(SolveError::Overflow(_))
+            ));
+            assert!(matches!(
+                answer(HUGE_IDS, Part::One),
+                Err(SolveError::Overflow(_))
+            ));
+        }
+
+        #[cfg(feature = "bigint")]
+        #[test]
+        fn test_big_answers() {
+            let twice = HUGE_POWER.repeat(2);
+
+            assert_eq!(
+                answer(HUGE_POWER, Part::Two).unwrap().to_string(),
+                "18446744073709551616"
+            );
+            assert_eq!(
+                answer(&twice, Part::Two).unwrap().to_string(),
+                "36893488147419103232"
+            );
+            assert_eq!(
+                answer(HUGE_IDS, Part::One).unwrap().to_string(),
+                "18446744073709551616"
+            );
+        }
+    }
+}
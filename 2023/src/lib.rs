@@ -0,0 +1,160 @@
+//! Solutions for Advent of Code 2023, one module per puzzle.
+
+pub mod cube_conundrum;
+pub mod trebuchet;
+
+pub use cube_conundrum::CubeConundrum;
+pub use trebuchet::Trebuchet;
+
+use aoc_core::Day;
+
+/// Every 2023 puzzle solved so far, in calendar order.
+pub const DAYS: &[Day] = &[
+    Day {
+        year: 2023,
+        day: 1,
+        title: "Trebuchet?!",
+        solver: &Trebuchet,
+        source: include_str!("trebuchet/mod.rs"),
+    },
+    Day {
+        year: 2023,
+        day: 2,
+        title: "Cube Conundrum",
+        solver: &CubeConundrum,
+        source: include_str!("cube_conundrum/mod.rs"),
+    },
+];
+
+mod test {
+    #[cfg(test)]
+    mod url {
+        use super::super::DAYS;
+
+        #[test]
+        fn test_header_urls() {
+            for day in DAYS {
+                let expected = format!("https://adventofcode.com/{}/day/{}", day.year, day.day);
+
+                assert_eq!(day.url(), Some(expected.as_str()));
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod examples {
+        use super::super::DAYS;
+        use aoc_core::Part;
+
+        #[test]
+        fn test_header_examples() {
+            for day in DAYS {
+                let examples = day.examples();
+
+                for part in Part::ALL {
+                    assert!(
+                        examples.iter().any(|example| example.part == part),
+                        "{} day {} has no example for part {part} in its header",
+                        day.year,
+                        day.day
+                    );
+                }
+
+                for example in examples {
+                    let input = day.solver.parse(&example.input).unwrap();
+                    let output = day.solver.part(input.as_ref(), example.part).unwrap();
+
+                    assert_eq!(
+                        output.to_string(),
+                        example.answer,
+                        "{} day {} part {}",
+                        day.year,
+                        day.day,
+                        example.part
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(all(test, feature = "parallel"))]
+    mod parallel {
+        use super::super::DAYS;
+        use aoc_core::Part;
+
+        #[test]
+        fn test_same_as_sequential() {
+            for day in DAYS {
+                for example in day.examples() {
+                    let long = example.input.repeat(50);
+                    let broken = format!("{long}\n{long}");
+
+                    for input in [&example.input, &long, &broken] {
+                        for part in Part::ALL {
+                            let sequential = match day.solver.parse(input) {
+                                Ok(parsed) => day.solver.part(parsed.as_ref(), part),
+                                Err(error) => Err(error.into()),
+                            };
+
+                            for threads in 1..8 {
+                                assert_eq!(
+                                    day.solver.solve(input, part, threads),
+                                    sequential,
+                                    "{} day {} part {part}, {threads} threads",
+                                    day.year,
+                                    day.day
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod solve_reader {
+        use super::super::DAYS;
+        use aoc_core::stream::ReadError;
+        use aoc_core::Part;
+
+        #[test]
+        fn test_same_as_parsed() {
+            for day in DAYS {
+                for example in day.examples() {
+                    let parsed = day.solver.parse(&example.input).unwrap();
+                    let expected = day.solver.part(parsed.as_ref(), example.part).unwrap();
+                    let mut reader = example.input.as_bytes();
+
+                    assert_eq!(
+                        day.solver
+                            .solve_reader(&mut reader, &[example.part])
+                            .unwrap(),
+                        vec![expected],
+                        "{} day {} part {}",
+                        day.year,
+                        day.day,
+                        example.part
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_errors_keep_the_line() {
+            for day in DAYS {
+                let example = day.examples()[0].input.trim_end().to_string();
+                let blank = example.lines().count() + 1;
+                let input = format!("{example}\n\n");
+                let mut reader = input.as_bytes();
+
+                let Err(ReadError::Parse { error, text }) =
+                    day.solver.solve_reader(&mut reader, &[Part::One])
+                else {
+                    panic!("{} day {} accepted a blank line", day.year, day.day);
+                };
+                assert_eq!((error.line, text.as_str()), (blank, ""));
+            }
+        }
+    }
+}
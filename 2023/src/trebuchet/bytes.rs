@@ -0,0 +1,295 @@
+//! Both parts over a whole input buffer, without splitting it into lines first.
+//!
+//! The regular parts copy every line into a [`CalibrationLine`] before
+//! looking at it, which dominates on very large generated documents. Here the
+//! buffer is scanned as is: newlines and ASCII digits are found eight bytes at
+//! a time with SWAR ("SIMD within a register") bit tricks, and spelled-out
+//! digits are only looked for from each end of a line up to the first hit.
+//!
+//! Both functions give the same sums as the parts of [`Trebuchet`], or `None`
+//! where those would fail or the sum would overflow; run the regular parts to
+//! find out which line is at fault.
+//!
+//! [`CalibrationLine`]: super::CalibrationLine
+//! [`Trebuchet`]: super::Trebuchet
+
+use std::sync::OnceLock;
+
+use super::matcher::Matcher;
+use super::vocabulary::{ENGLISH, NUMERALS};
+
+const ONES: u64 = 0x0101_0101_0101_0101;
+const HIGHS: u64 = 0x8080_8080_8080_8080;
+const LOWS: u64 = !HIGHS;
+
+/// The high bit of every byte of `word` that equals `byte`.
+#[inline]
+fn byte_mask(word: u64, byte: u8) -> u64 {
+    let x = word ^ (ONES * u64::from(byte));
+
+    // A byte of `x` is zero when neither its low seven bits (which would
+    // carry into the high bit) nor its high bit are set. No carry crosses
+    // into the next byte.
+    !(((x & LOWS) + LOWS) | x | LOWS)
+}
+
+/// The high bit of every byte of `word` that is an ASCII digit.
+#[inline]
+fn digit_mask(word: u64) -> u64 {
+    let low = word & LOWS;
+    let from_zero = low + ONES * u64::from(0x80 - b'0');
+    let past_nine = low + ONES * u64::from(0x80 - b'9' - 1);
+
+    from_zero & !past_nine & !word & HIGHS
+}
+
+#[inline]
+fn load(chunk: &[u8]) -> u64 {
+    u64::from_le_bytes(chunk.try_into().expect("chunks are eight bytes"))
+}
+
+/// The offset of the first newline in `bytes`.
+fn find_newline(bytes: &[u8]) -> Option<usize> {
+    let mut chunks = bytes.chunks_exact(8);
+    for (index, chunk) in chunks.by_ref().enumerate() {
+        let mask = byte_mask(load(chunk), b'\n');
+        if mask != 0 {
+            return Some(index * 8 + mask.trailing_zeros() as usize / 8);
+        }
+    }
+
+    let scanned = bytes.len() - chunks.remainder().len();
+    let tail = chunks.remainder().iter().position(|&byte| byte == b'\n');
+    tail.map(|position| scanned + position)
+}
+
+/// `buffer` without the line ending after its last line, which like in
+/// [`aoc_core::lines`] does not start an extra empty line.
+fn trim_end(buffer: &[u8]) -> &[u8] {
+    match buffer.strip_suffix(b"\n") {
+        Some(rest) => rest.strip_suffix(b"\r").unwrap_or(rest),
+        None => buffer,
+    }
+}
+
+/// The lines of `buffer`, without their `\n`. A `\r` before the newline
+/// stays on the line, where it is just filler.
+fn lines(buffer: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let mut rest = Some(trim_end(buffer)).filter(|rest| !rest.is_empty());
+
+    std::iter::from_fn(move || {
+        let text = rest?;
+        let Some(end) = find_newline(text) else {
+            rest = None;
+            return Some(text);
+        };
+        rest = Some(&text[end + 1..]);
+
+        Some(&text[..end])
+    })
+}
+
+/// The first and last ASCII digit of the current line.
+#[derive(Default)]
+struct Ends {
+    first: Option<u8>,
+    last: u8,
+}
+
+impl Ends {
+    #[inline]
+    fn digit(&mut self, byte: u8) {
+        let value = byte - b'0';
+        self.first.get_or_insert(value);
+        self.last = value;
+    }
+
+    /// The line's calibration value, resetting for the next line.
+    #[inline]
+    fn finish(&mut self) -> Option<usize> {
+        let first = self.first.take()?;
+
+        Some(usize::from(first) * 10 + usize::from(self.last))
+    }
+}
+
+/// The sum of part one over `buffer`, in a single pass that visits each digit
+/// and newline but skips over other bytes eight at a time.
+pub fn part1(buffer: &[u8]) -> Option<usize> {
+    let mut sum = 0;
+    let mut ends = Ends::default();
+    let mut visit = |byte: u8, ends: &mut Ends| -> Option<()> {
+        if byte == b'\n' {
+            sum = ends.finish()?.checked_add(sum)?;
+        } else {
+            ends.digit(byte);
+        }
+        Some(())
+    };
+
+    let buffer = trim_end(buffer);
+    let mut chunks = buffer.chunks_exact(8);
+    for chunk in chunks.by_ref() {
+        let word = load(chunk);
+        let mut mask = digit_mask(word) | byte_mask(word, b'\n');
+        while mask != 0 {
+            visit(chunk[mask.trailing_zeros() as usize / 8], &mut ends)?;
+            mask &= mask - 1;
+        }
+    }
+    for &byte in chunks.remainder() {
+        if byte == b'\n' || byte.is_ascii_digit() {
+            visit(byte, &mut ends)?;
+        }
+    }
+
+    if !buffer.is_empty() {
+        sum = ends.finish()?.checked_add(sum)?;
+    }
+
+    Some(sum)
+}
+
+/// Recognizes numerals and English words, forwards and backwards.
+fn matchers() -> &'static (Matcher<u8>, Matcher<u8>) {
+    static MATCHERS: OnceLock<(Matcher<u8>, Matcher<u8>)> = OnceLock::new();
+
+    MATCHERS.get_or_init(|| {
+        let spellings: Vec<(String, u8)> = NUMERALS
+            .into_iter()
+            .zip(0..)
+            .chain(ENGLISH.into_iter().zip(0..))
+            .map(|(spelling, value)| (spelling.to_string(), value))
+            .collect();
+        let reversed: Vec<(String, u8)> = spellings
+            .iter()
+            .map(|(spelling, value)| (spelling.chars().rev().collect(), *value))
+            .collect();
+
+        let forward = spellings
+            .iter()
+            .map(|(spelling, value)| (spelling.as_str(), *value));
+        let backward = reversed
+            .iter()
+            .map(|(spelling, value)| (spelling.as_str(), *value));
+        let fits = "the digit spellings fit in a matcher";
+        (
+            Matcher::new(forward).expect(fits),
+            Matcher::new(backward).expect(fits),
+        )
+    })
+}
+
+/// The sum of part two over `buffer`. Each line is searched from the front
+/// for its first digit and from the back for its last, so the middle of a
+/// long line is never looked at.
+pub fn part2(buffer: &[u8]) -> Option<usize> {
+    let (forward, backward) = matchers();
+
+    lines(buffer).try_fold(0, |sum, line| {
+        let first = forward.first_value(line.iter().copied())?;
+        let last = backward.first_value(line.iter().rev().copied())?;
+
+        usize::checked_add(sum, usize::from(first) * 10 + usize::from(last))
+    })
+}
+
+mod test {
+    #[cfg(test)]
+    mod masks {
+        use super::super::{byte_mask, digit_mask, find_newline, load};
+
+        #[test]
+        fn test_every_byte_value() {
+            for byte in 0..=255u8 {
+                let chunk = [b'a', byte, 0xFF, byte, 0x00, b'\n', b'0', b'9'];
+                let word = load(&chunk);
+
+                for (lane, &other) in chunk.iter().enumerate() {
+                    let bit = 0x80u64 << (lane * 8);
+                    assert_eq!(byte_mask(word, byte) & bit != 0, other == byte);
+                    assert_eq!(digit_mask(word) & bit != 0, other.is_ascii_digit());
+                }
+            }
+        }
+
+        #[test]
+        fn test_find_newline() {
+            assert_eq!(find_newline(b""), None);
+            assert_eq!(find_newline(b"abc"), None);
+            assert_eq!(find_newline(b"abcdefgh\n"), Some(8));
+            assert_eq!(find_newline(b"abcdefghijk\nxy\n"), Some(11));
+            assert_eq!(find_newline(b"ab\ncdefghijk"), Some(2));
+        }
+    }
+
+    #[cfg(test)]
+    mod differential {
+        use aoc_core::rng::Rng;
+        use aoc_core::{Answer, Solution};
+
+        use super::super::{part1, part2};
+        use crate::Trebuchet;
+
+        const TOKENS: [&str; 18] = [
+            "a", "xyz", "q", "7", "0", "one", "two", "eight", "nine", "twone", "oneight",
+            "eightwo", "sevenine", "eeeight", "\r", "é", "½", "٣",
+        ];
+
+        fn document(rng: &mut Rng) -> String {
+            let mut document = String::new();
+            for _ in 0..rng.below(12) {
+                for _ in 0..rng.below(30) {
+                    let token = TOKENS[rng.below(TOKENS.len())];
+                    document.push_str(token);
+                }
+                document.push('\n');
+            }
+            if rng.chance(0.3) {
+                document.pop();
+            }
+
+            document
+        }
+
+        #[test]
+        fn test_matches_regular_parts() {
+            let mut rng = Rng::new(15);
+
+            for _ in 0..2_000 {
+                let document = document(&mut rng);
+                let input = Trebuchet::parse(&document);
+                let expected1 = input
+                    .as_ref()
+                    .ok()
+                    .and_then(|input| Trebuchet::part1(input).ok());
+                let expected2 = input
+                    .as_ref()
+                    .ok()
+                    .and_then(|input| Trebuchet::part2(input).ok());
+
+                assert_eq!(
+                    part1(document.as_bytes()).map(Answer::from),
+                    expected1,
+                    "{document:?}"
+                );
+                assert_eq!(
+                    part2(document.as_bytes()).map(Answer::from),
+                    expected2,
+                    "{document:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_edges() {
+            assert_eq!(part1(b""), Some(0));
+            assert_eq!(part2(b""), Some(0));
+            assert_eq!(part1(b"\r\n"), Some(0));
+            assert_eq!(part1(b"1abc2\n"), Some(12));
+            assert_eq!(part1(b"1abc2\n\n"), None);
+            assert_eq!(part2(b"xtwone3four\r\n4nineeightseven2"), Some(24 + 42));
+            assert_eq!(part1(b"pqrstuvwxyzpqrstuvwx\n12"), None);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Line by line traces of how each calibration value was read, for checking
+//! the decoder against a document by eye.
+
+use std::fmt;
+
+use aoc_core::lines::{lines, BlankLines};
+use aoc_core::Part;
+
+use super::scanner::{self, DigitMatch};
+
+/// Which lines [`explain`] keeps.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Show {
+    #[default]
+    All,
+    /// Only lines whose calibration value differs between the two parts.
+    Disagreements,
+}
+
+/// The digits both parts read on one line.
+///
+/// Displays as the part two reading, with the part one reading appended when
+/// it differs:
+///
+/// ```text
+/// xtwone3four -> two@1, four@7 = 24 (part 1: 3@6, 3@6 = 33)
+/// ```
+///
+/// Each digit is shown as written, so `two@1` is a spelled-out word and `3@6`
+/// a numeral; the number after `@` is its byte offset in the line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trace<'a> {
+    /// 1-based position in the raw input.
+    pub number: usize,
+    pub text: &'a str,
+    /// First and last digit for part one, numerals only.
+    pub part1: Option<(DigitMatch, DigitMatch)>,
+    /// First and last digit for part two, numerals and English words.
+    pub part2: Option<(DigitMatch, DigitMatch)>,
+}
+
+impl<'a> Trace<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Trace {
+            number,
+            text,
+            part1: scanner::numerals().first_and_last_digit(text),
+            part2: scanner::first_and_last_digit(text),
+        }
+    }
+
+    /// The first and last digit `part` reads, if the line has any.
+    pub fn digits(&self, part: Part) -> Option<&(DigitMatch, DigitMatch)> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    /// The calibration value `part` reads, if the line has any digit.
+    pub fn value(&self, part: Part) -> Option<usize> {
+        self.digits(part).map(|(first, last)| value(first, last))
+    }
+
+    pub fn disagrees(&self) -> bool {
+        self.value(Part::One) != self.value(Part::Two)
+    }
+
+    fn write_reading(&self, f: &mut fmt::Formatter<'_>, part: Part) -> fmt::Result {
+        match self.digits(part) {
+            Some((first, last)) => write!(
+                f,
+                "{}, {} = {}",
+                self.spelling(first),
+                self.spelling(last),
+                value(first, last)
+            ),
+            None => f.write_str("no digit"),
+        }
+    }
+
+    fn spelling(&self, digit: &DigitMatch) -> String {
+        format!("{}@{}", &self.text[digit.range.clone()], digit.range.start)
+    }
+}
+
+impl fmt::Display for Trace<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> ", self.text)?;
+        self.write_reading(f, Part::Two)?;
+        if self.disagrees() {
+            f.write_str(" (part 1: ")?;
+            self.write_reading(f, Part::One)?;
+            f.write_str(")")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Traces every non-blank line of `input`, keeping those `show` asks for.
+/// Lines without a digit are traced too, rather than stopping at the first.
+pub fn explain(input: &str, show: Show) -> Vec<Trace<'_>> {
+    lines(input, BlankLines::Skip)
+        .filter_map(Result::ok)
+        .map(|line| Trace::new(line.number, line.text))
+        .filter(|trace| show == Show::All || trace.disagrees())
+        .collect()
+}
+
+fn value(first: &DigitMatch, last: &DigitMatch) -> usize {
+    usize::from(first.value) * 10 + usize::from(last.value)
+}
+
+mod test {
+    #[cfg(test)]
+    mod explain {
+        use super::super::{explain, Show, Trace};
+        use crate::trebuchet::scanner::DigitKind;
+        use aoc_core::Part;
+
+        #[test]
+        fn test_trace() {
+            let trace = Trace::new(1, "xtwone3four");
+            let (first, last) = trace.digits(Part::Two).unwrap();
+
+            assert_eq!((first.kind, last.kind), (DigitKind::Word, DigitKind::Word));
+            assert_eq!((first.range.start, last.range.start), (1, 7));
+            assert_eq!(trace.value(Part::One), Some(33));
+            assert_eq!(trace.value(Part::Two), Some(24));
+            assert_eq!(
+                trace.to_string(),
+                "xtwone3four -> two@1, four@7 = 24 (part 1: 3@6, 3@6 = 33)"
+            );
+        }
+
+        #[test]
+        fn test_agreeing_line() {
+            assert_eq!(
+                Trace::new(1, "pqr3stu8vwx").to_string(),
+                "pqr3stu8vwx -> 3@3, 8@7 = 38"
+            );
+        }
+
+        #[test]
+        fn test_line_without_numerals() {
+            assert_eq!(
+                Trace::new(1, "eightwothree").to_string(),
+                "eightwothree -> eight@0, three@7 = 83 (part 1: no digit)"
+            );
+            assert_eq!(Trace::new(1, "abc").to_string(), "abc -> no digit");
+        }
+
+        #[test]
+        fn test_only_disagreements() {
+            let input = "1abc2\ntwo1nine\n\ntreb7uchet\nabcone2threexyz\n";
+            let numbers = |show| -> Vec<usize> {
+                explain(input, show)
+                    .into_iter()
+                    .map(|trace| trace.number)
+                    .collect()
+            };
+
+            assert_eq!(numbers(Show::All), vec![1, 2, 4, 5]);
+            assert_eq!(numbers(Show::Disagreements), vec![2, 5]);
+        }
+    }
+}
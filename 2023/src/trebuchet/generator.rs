@@ -0,0 +1,296 @@
+//! Seeded calibration documents of any size, with their answers.
+//!
+//! A line is a run of tokens: numerals, spelled-out digits, overlapping
+//! spellings like `oneight` and filler letters. The generator knows which
+//! digits each token holds, so it works out both answers from the tokens it
+//! picked instead of scanning the text it wrote, which makes them a fair
+//! reference for the decoder. Tokens are always kept apart by filler, and
+//! filler letters appear in no digit word, so no digit can straddle two
+//! tokens.
+
+use std::ops::RangeInclusive;
+
+use aoc_core::rng::Rng;
+
+use super::vocabulary::ENGLISH;
+
+/// Letters that are in none of the words `zero` to `nine`.
+const FILLER: &[u8] = b"abcdjklmpqy";
+
+/// Spellings that share letters, or hide a digit behind a false start, with
+/// the first and last digit part two reads in each.
+const OVERLAPS: [(&str, u8, u8); 12] = [
+    ("oneight", 1, 8),
+    ("twone", 2, 1),
+    ("threeight", 3, 8),
+    ("fiveight", 5, 8),
+    ("sevenine", 7, 9),
+    ("eightwo", 8, 2),
+    ("eighthree", 8, 3),
+    ("nineight", 9, 8),
+    ("zerone", 0, 1),
+    ("oneightwo", 1, 2),
+    ("eeeight", 8, 8),
+    ("ninine", 9, 9),
+];
+
+/// A generated line and its calibration value in each part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GeneratedLine {
+    pub text: String,
+    pub part1: u64,
+    pub part2: u64,
+}
+
+/// A generated document, newline-terminated, and its answer to each part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GeneratedDocument {
+    pub text: String,
+    pub part1: u64,
+    pub part2: u64,
+}
+
+/// Produces calibration lines from a seed. Densities are the share of tokens
+/// of each kind and the rest is filler; every line gets at least one numeral
+/// so that part one has an answer.
+#[derive(Clone, Debug)]
+pub struct Generator {
+    rng: Rng,
+    tokens: RangeInclusive<usize>,
+    digits: f64,
+    words: f64,
+    overlaps: f64,
+}
+
+impl Generator {
+    /// 4 to 16 tokens a line, a fifth of them numerals, a fifth spelled-out
+    /// digits and a tenth overlaps.
+    pub fn new(seed: u64) -> Self {
+        Generator {
+            rng: Rng::new(seed),
+            tokens: 4..=16,
+            digits: 0.2,
+            words: 0.2,
+            overlaps: 0.1,
+        }
+    }
+
+    /// How many tokens a line has, picked evenly from `tokens`.
+    pub fn tokens(mut self, tokens: RangeInclusive<usize>) -> Self {
+        assert!(!tokens.is_empty(), "a line needs a number of tokens");
+        self.tokens = tokens;
+        self
+    }
+
+    /// The share of tokens that are numerals, spelled-out digits and
+    /// overlapping spellings. Panics unless they add up to at most 1.
+    pub fn densities(mut self, digits: f64, words: f64, overlaps: f64) -> Self {
+        let densities = [digits, words, overlaps];
+        assert!(
+            densities
+                .iter()
+                .all(|density| (0.0..=1.0).contains(density))
+                && densities.iter().sum::<f64>() <= 1.0,
+            "densities must be between 0 and 1 and add up to at most 1"
+        );
+        (self.digits, self.words, self.overlaps) = (digits, words, overlaps);
+        self
+    }
+
+    /// The next line, without its terminator.
+    pub fn line(&mut self) -> GeneratedLine {
+        let count =
+            self.rng.below(self.tokens.end() - self.tokens.start() + 1) + self.tokens.start();
+        let mut tokens: Vec<Token> = (0..count).map(|_| self.token()).collect();
+        if !tokens.iter().any(|token| token.numeral) {
+            let numeral = self.numeral();
+            tokens.insert(self.rng.below(tokens.len() + 1), numeral);
+        }
+
+        let mut text = String::new();
+        for (index, token) in tokens.iter().enumerate() {
+            if index > 0 && !(token.is_filler() && tokens[index - 1].is_filler()) {
+                self.push_filler(&mut text, 1);
+            }
+            text.push_str(&token.text);
+        }
+
+        let numerals: Vec<&Token> = tokens.iter().filter(|token| token.numeral).collect();
+        let spelled: Vec<&Token> = tokens.iter().filter(|token| !token.is_filler()).collect();
+        let value = |first: &Token, last: &Token| {
+            u64::from(first.digits.unwrap().0) * 10 + u64::from(last.digits.unwrap().1)
+        };
+
+        GeneratedLine {
+            text,
+            part1: value(numerals[0], numerals[numerals.len() - 1]),
+            part2: value(spelled[0], spelled[spelled.len() - 1]),
+        }
+    }
+
+    /// A document of `lines` lines.
+    pub fn document(&mut self, lines: usize) -> GeneratedDocument {
+        let mut document = GeneratedDocument {
+            text: String::new(),
+            part1: 0,
+            part2: 0,
+        };
+
+        for _ in 0..lines {
+            let line = self.line();
+            document.text.push_str(&line.text);
+            document.text.push('\n');
+            document.part1 += line.part1;
+            document.part2 += line.part2;
+        }
+
+        document
+    }
+
+    fn token(&mut self) -> Token {
+        if self.rng.chance(self.digits) {
+            return self.numeral();
+        }
+        // Each further kind is drawn from what is left, so that overall it
+        // makes up its own density of the tokens.
+        let mut left = 1.0 - self.digits;
+        if self.rng.chance(self.words / left) {
+            let value = self.rng.below(10) as u8;
+            return Token::spelled(ENGLISH[usize::from(value)], value, value);
+        }
+        left -= self.words;
+        if self.rng.chance(self.overlaps / left) {
+            let (text, first, last) = OVERLAPS[self.rng.below(OVERLAPS.len())];
+            return Token::spelled(text, first, last);
+        }
+
+        let mut text = String::new();
+        let length = self.rng.below(3) + 1;
+        self.push_filler(&mut text, length);
+        Token {
+            text,
+            digits: None,
+            numeral: false,
+        }
+    }
+
+    fn numeral(&mut self) -> Token {
+        let value = self.rng.below(10) as u8;
+        Token {
+            text: char::from(b'0' + value).to_string(),
+            digits: Some((value, value)),
+            numeral: true,
+        }
+    }
+
+    fn push_filler(&mut self, text: &mut String, length: usize) {
+        for _ in 0..length {
+            text.push(char::from(FILLER[self.rng.below(FILLER.len())]));
+        }
+    }
+}
+
+/// One piece of a line: the first and last digit part two reads in it, if
+/// any, and whether it is a numeral that part one reads too.
+struct Token {
+    text: String,
+    digits: Option<(u8, u8)>,
+    numeral: bool,
+}
+
+impl Token {
+    fn spelled(text: &str, first: u8, last: u8) -> Self {
+        Token {
+            text: text.to_string(),
+            digits: Some((first, last)),
+            numeral: false,
+        }
+    }
+
+    fn is_filler(&self) -> bool {
+        self.digits.is_none()
+    }
+}
+
+mod test {
+    #[cfg(test)]
+    mod generator {
+        use super::super::{Generator, FILLER, OVERLAPS};
+        use crate::trebuchet::vocabulary::ENGLISH;
+        use crate::trebuchet::{bytes, get_line_digits};
+        use crate::Trebuchet;
+        use aoc_core::{Answer, Solution};
+
+        #[test]
+        fn test_same_seed_same_document() {
+            assert_eq!(
+                Generator::new(5).document(50),
+                Generator::new(5).document(50)
+            );
+            assert_ne!(
+                Generator::new(5).document(50).text,
+                Generator::new(6).document(50).text
+            );
+        }
+
+        #[test]
+        fn test_filler_spells_no_digit() {
+            for word in ENGLISH.iter().chain(OVERLAPS.iter().map(|(text, ..)| text)) {
+                assert!(
+                    !word.bytes().any(|letter| FILLER.contains(&letter)),
+                    "{word}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_overlap_digits() {
+            for (text, first, last) in OVERLAPS {
+                let value = usize::from(first) * 10 + usize::from(last);
+                assert_eq!(get_line_digits(text), Some(value), "{text}");
+            }
+        }
+
+        #[test]
+        fn test_densities() {
+            let document = Generator::new(1).densities(0.0, 0.0, 0.0).document(100);
+
+            for line in document.text.lines() {
+                assert_eq!(line.bytes().filter(u8::is_ascii_digit).count(), 1);
+                assert!(ENGLISH.iter().all(|word| !line.contains(word)));
+            }
+            assert_eq!(document.part1, document.part2);
+        }
+
+        #[test]
+        #[should_panic(expected = "densities")]
+        fn test_densities_above_one() {
+            let _ = Generator::new(1).densities(0.6, 0.6, 0.0);
+        }
+
+        #[test]
+        fn test_answers_match_solver() {
+            let settings = [
+                (0.2, 0.2, 0.1),
+                (0.0, 0.0, 1.0),
+                (0.05, 0.5, 0.4),
+                (1.0, 0.0, 0.0),
+                (0.0, 0.0, 0.0),
+            ];
+
+            for (seed, (digits, words, overlaps)) in settings.into_iter().enumerate() {
+                let document = Generator::new(seed as u64)
+                    .tokens(1..=20)
+                    .densities(digits, words, overlaps)
+                    .document(500);
+                let input = Trebuchet::parse(&document.text).unwrap();
+                let text = document.text.as_bytes();
+
+                assert_eq!(Trebuchet::part1(&input), Ok(Answer::from(document.part1)));
+                assert_eq!(Trebuchet::part2(&input), Ok(Answer::from(document.part2)));
+                assert_eq!(bytes::part1(text), Some(document.part1 as usize));
+                assert_eq!(bytes::part2(text), Some(document.part2 as usize));
+            }
+        }
+    }
+}
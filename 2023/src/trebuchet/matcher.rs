@@ -0,0 +1,452 @@
+//! Finding the first and last digit of a line in a single pass.
+//!
+//! All spellings of all digits are compiled into one Aho–Corasick automaton,
+//! so each byte of the line is looked at exactly once no matter how many
+//! spellings there are or how they overlap ("twone", "eightwo"). Finding the
+//! first and last match does not allocate.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::Range;
+
+/// A spelling that ends in a given state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Hit<T> {
+    value: T,
+    len: u32,
+}
+
+/// An occurrence of a spelling, by byte range within the scanned text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match<T> {
+    pub value: T,
+    pub range: Range<usize>,
+}
+
+/// The spellings given to a [`Matcher`] need more states than it can number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TooLarge;
+
+impl fmt::Display for TooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "too many spellings to match at once")
+    }
+}
+
+impl std::error::Error for TooLarge {}
+
+pub struct Matcher<T> {
+    /// Maps each byte to its column in `delta`; bytes that appear in no
+    /// spelling all share column 0.
+    classes: [u16; 256],
+    class_count: usize,
+    /// The full transition table, `delta[state * class_count + class]`.
+    delta: Vec<u16>,
+    /// The spelling that leads exactly to each state, if any.
+    own: Vec<Option<Hit<T>>>,
+    /// The nearest proper suffix state that has an `own` spelling, or 0.
+    output_link: Vec<u16>,
+    /// The longest spelling ending in each state, if any.
+    longest: Vec<Option<Hit<T>>>,
+    /// The shortest spelling ending in each state, if any.
+    shortest: Vec<Option<Hit<T>>>,
+    /// How many bytes of a spelling each state has read.
+    depth: Vec<u32>,
+}
+
+impl<T: Copy> Matcher<T> {
+    /// Builds a matcher recognizing each `(spelling, value)` pair. When the
+    /// same spelling is given twice, the first value wins. States are
+    /// numbered in 16 bits, so the spellings may share at most 65535 distinct
+    /// prefixes.
+    pub fn new<'a>(spellings: impl IntoIterator<Item = (&'a str, T)>) -> Result<Self, TooLarge> {
+        Matcher::build(spellings, false)
+    }
+
+    /// Like [`Matcher::new`], but ASCII letters match regardless of case.
+    pub fn new_ascii_case_insensitive<'a>(
+        spellings: impl IntoIterator<Item = (&'a str, T)>,
+    ) -> Result<Self, TooLarge> {
+        Matcher::build(spellings, true)
+    }
+
+    fn build<'a>(
+        spellings: impl IntoIterator<Item = (&'a str, T)>,
+        fold_case: bool,
+    ) -> Result<Self, TooLarge> {
+        let spellings: Vec<(Vec<u8>, T)> = spellings
+            .into_iter()
+            .filter(|(spelling, _)| !spelling.is_empty())
+            .map(|(spelling, value)| match fold_case {
+                true => (spelling.to_ascii_lowercase().into_bytes(), value),
+                false => (spelling.as_bytes().to_vec(), value),
+            })
+            .collect();
+
+        let mut classes = [0u16; 256];
+        let mut class_count = 1;
+        for (spelling, _) in &spellings {
+            for &byte in spelling {
+                if classes[byte as usize] == 0 {
+                    classes[byte as usize] = class_count as u16;
+                    class_count += 1;
+                }
+            }
+        }
+        if fold_case {
+            for byte in b'A'..=b'Z' {
+                classes[byte as usize] = classes[byte.to_ascii_lowercase() as usize];
+            }
+        }
+
+        // Build the trie; `goto` uses 0 for "no edge" since the root is never
+        // a child.
+        let mut goto: Vec<Vec<u16>> = vec![vec![0; class_count]];
+        let mut own: Vec<Option<Hit<T>>> = vec![None];
+        let mut depth: Vec<u32> = vec![0];
+        for (spelling, value) in &spellings {
+            let mut state = 0;
+            for &byte in spelling {
+                let class = classes[byte as usize] as usize;
+                if goto[state][class] == 0 {
+                    goto.push(vec![0; class_count]);
+                    own.push(None);
+                    depth.push(depth[state] + 1);
+                    goto[state][class] = u16::try_from(goto.len() - 1).map_err(|_| TooLarge)?;
+                }
+                state = goto[state][class] as usize;
+            }
+            own[state].get_or_insert(Hit {
+                value: *value,
+                len: spelling.len() as u32,
+            });
+        }
+
+        // Breadth-first, turn the trie into a complete automaton and collect
+        // the spellings that end in each state through its suffixes.
+        let state_count = goto.len();
+        let mut delta = vec![0u16; state_count * class_count];
+        let mut fail = vec![0usize; state_count];
+        let mut output_link = vec![0u16; state_count];
+        let mut longest = own.clone();
+        let mut shortest = own.clone();
+        let mut queue = VecDeque::new();
+
+        for class in 0..class_count {
+            let child = goto[0][class] as usize;
+            delta[class] = child as u16;
+            if child != 0 {
+                queue.push_back(child);
+            }
+        }
+        while let Some(state) = queue.pop_front() {
+            let suffix = fail[state];
+            output_link[state] = if own[suffix].is_some() {
+                suffix as u16
+            } else {
+                output_link[suffix]
+            };
+            longest[state] = longest[state].or(longest[suffix]);
+            shortest[state] = match (shortest[state], shortest[suffix]) {
+                (Some(hit), Some(other)) if other.len < hit.len => Some(other),
+                (hit, other) => hit.or(other),
+            };
+
+            for class in 0..class_count {
+                let child = goto[state][class] as usize;
+                let fallback = delta[suffix * class_count + class];
+                if child == 0 {
+                    delta[state * class_count + class] = fallback;
+                } else {
+                    delta[state * class_count + class] = child as u16;
+                    fail[child] = fallback as usize;
+                    queue.push_back(child);
+                }
+            }
+        }
+
+        Ok(Matcher {
+            classes,
+            class_count,
+            delta,
+            own,
+            output_link,
+            longest,
+            shortest,
+            depth,
+        })
+    }
+
+    /// The spelling that starts first in `text`, the longest one if several
+    /// start there.
+    pub fn first(&self, text: &str) -> Option<Match<T>> {
+        self.earliest(text.bytes())
+    }
+
+    /// The value of the spelling that starts first in `bytes`, fed in
+    /// whatever order the caller likes; a matcher built from reversed
+    /// spellings and fed a line back to front finds the spelling that ends
+    /// last.
+    pub fn first_value(&self, bytes: impl IntoIterator<Item = u8>) -> Option<T> {
+        self.earliest(bytes).map(|found| found.value)
+    }
+
+    /// The spelling that starts first and the one that starts last in
+    /// `text`, preferring the longest of those that start at the same byte.
+    pub fn first_last(&self, text: &str) -> Option<(Match<T>, Match<T>)> {
+        let mut state = 0;
+        let mut first: Option<Match<T>> = None;
+        let mut last: Option<Match<T>> = None;
+
+        for (position, &byte) in text.as_bytes().iter().enumerate() {
+            state = self.step(state, byte);
+            let Some(shortest) = self.shortest[state] else {
+                continue;
+            };
+
+            // Of the spellings ending here, the longest starts earliest and
+            // the shortest latest. A later end with the same start is a
+            // longer spelling, so ties go to the newer match.
+            let longest = self.longest[state].unwrap_or(shortest);
+            let earlier = longest.at(position);
+            if first
+                .as_ref()
+                .is_none_or(|found| earlier.range.start <= found.range.start)
+            {
+                first = Some(earlier);
+            }
+            let later = shortest.at(position);
+            if last
+                .as_ref()
+                .is_none_or(|found| later.range.start >= found.range.start)
+            {
+                last = Some(later);
+            }
+        }
+
+        Some((first?, last?))
+    }
+
+    /// The spelling that starts first in `bytes`, stopping as soon as no
+    /// spelling still being read could start before it.
+    fn earliest(&self, bytes: impl IntoIterator<Item = u8>) -> Option<Match<T>> {
+        let mut state = 0;
+        let mut found: Option<Match<T>> = None;
+
+        for (position, byte) in bytes.into_iter().enumerate() {
+            state = self.step(state, byte);
+            // The spellings in progress all start at or after this byte.
+            let reading_from = position + 1 - self.depth[state] as usize;
+            if let Some(best) = &found {
+                if reading_from > best.range.start {
+                    break;
+                }
+            }
+
+            if let Some(hit) = self.longest[state] {
+                let candidate = hit.at(position);
+                if found
+                    .as_ref()
+                    .is_none_or(|best| candidate.range.start <= best.range.start)
+                {
+                    found = Some(candidate);
+                }
+            }
+        }
+
+        found
+    }
+
+    /// Every occurrence of every spelling in `text`, overlapping ones
+    /// included, ordered by where they end and then longest first.
+    pub fn matches<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Match<T>> + 'a {
+        let mut state = 0;
+
+        text.as_bytes()
+            .iter()
+            .enumerate()
+            .flat_map(move |(position, &byte)| {
+                state = self.step(state, byte);
+                let mut next = if self.own[state].is_some() {
+                    state
+                } else {
+                    self.output_link[state] as usize
+                };
+
+                std::iter::from_fn(move || {
+                    let hit = self.own[next]?;
+                    next = self.output_link[next] as usize;
+                    Some(hit.at(position))
+                })
+            })
+    }
+
+    #[inline]
+    fn step(&self, state: usize, byte: u8) -> usize {
+        let class = self.classes[byte as usize] as usize;
+        self.delta[state * self.class_count + class] as usize
+    }
+}
+
+impl<T> Hit<T> {
+    /// The match for this hit when it ends with the byte at `position`.
+    fn at(self, position: usize) -> Match<T> {
+        Match {
+            value: self.value,
+            range: position + 1 - self.len as usize..position + 1,
+        }
+    }
+}
+
+mod test {
+    #[cfg(test)]
+    mod first_last {
+        use super::super::Matcher;
+
+        fn matcher() -> Matcher<u8> {
+            Matcher::new([("1", 1), ("one", 1), ("two", 2), ("eight", 8), ("three", 3)]).unwrap()
+        }
+
+        fn values(text: &str) -> Option<(u8, u8)> {
+            matcher()
+                .first_last(text)
+                .map(|(first, last)| (first.value, last.value))
+        }
+
+        #[test]
+        fn test_overlaps() {
+            assert_eq!(values("twone"), Some((2, 1)));
+            assert_eq!(values("eightwo"), Some((8, 2)));
+            assert_eq!(values("eighthree"), Some((8, 3)));
+        }
+
+        #[test]
+        fn test_ranges() {
+            let (first, last) = matcher().first_last("xtwone1x").unwrap();
+
+            assert_eq!(first.range, 1..4);
+            assert_eq!(last.range, 6..7);
+            assert_eq!(matcher().first("aatwo").unwrap().range, 2..5);
+        }
+
+        #[test]
+        fn test_no_match() {
+            assert_eq!(values("ttwwoo"), None);
+            assert_eq!(matcher().first(""), None);
+        }
+
+        #[test]
+        fn test_nested_spellings() {
+            // "ne" is a suffix of both other spellings.
+            let matcher = Matcher::new([("one", 1), ("ne", 5), ("nine", 9)]).unwrap();
+            let values = |text| {
+                matcher
+                    .first_last(text)
+                    .map(|(first, last)| (first.value, last.value))
+            };
+
+            assert_eq!(values("xone"), Some((1, 5)));
+            assert_eq!(values("onine"), Some((9, 5)));
+            assert_eq!(values("nex"), Some((5, 5)));
+        }
+
+        #[test]
+        fn test_long_overlaps() {
+            // "bcd" starts after "abc" even though the two share two bytes.
+            let matcher = Matcher::new([("abc", 1), ("bcd", 2), ("cdefg", 3)]).unwrap();
+            let values = |text| {
+                matcher
+                    .first_last(text)
+                    .map(|(first, last)| (first.value, last.value))
+            };
+
+            assert_eq!(values("abcd"), Some((1, 2)));
+            assert_eq!(values("xabcdefg"), Some((1, 3)));
+            assert_eq!(matcher.first("xbcdefg").unwrap().value, 2);
+        }
+
+        #[test]
+        fn test_inner_spelling() {
+            // "bc" ends first, but "abcd" starts first.
+            let matcher = Matcher::new([("abcd", 1), ("bc", 2), ("ab", 3)]).unwrap();
+
+            assert_eq!(matcher.first("xabcd").unwrap().range, 1..5);
+            assert_eq!(matcher.first_value("xabcd".bytes()), Some(1));
+            assert_eq!(matcher.first("xabce").unwrap().value, 3);
+            let (first, last) = matcher.first_last("abcd").unwrap();
+            assert_eq!((first.value, last.value), (1, 2));
+        }
+    }
+
+    #[cfg(test)]
+    mod build {
+        use super::super::{Matcher, TooLarge};
+
+        #[test]
+        fn test_too_many_states() {
+            let spellings: Vec<String> = (0..100_000).map(|number| number.to_string()).collect();
+            let matcher = |count: usize| {
+                Matcher::new(
+                    spellings[..count]
+                        .iter()
+                        .map(|spelling| (spelling.as_str(), ())),
+                )
+            };
+
+            assert!(matcher(10_000).is_ok());
+            assert_eq!(matcher(100_000).err(), Some(TooLarge));
+        }
+    }
+
+    #[cfg(test)]
+    mod matches {
+        use super::super::{Match, Matcher};
+
+        #[test]
+        fn test_ascii_case_insensitive() {
+            let matcher = Matcher::new_ascii_case_insensitive([("One", 1), ("two", 2)]).unwrap();
+            let ranges: Vec<_> = matcher
+                .matches("ONE-tWo-one")
+                .map(|found| found.range)
+                .collect();
+
+            assert_eq!(ranges, vec![0..3, 4..7, 8..11]);
+            assert_eq!(
+                Matcher::new([("one", 1)]).unwrap().matches("ONE").count(),
+                0
+            );
+        }
+
+        #[test]
+        fn test_all_overlapping_matches() {
+            let matcher = Matcher::new([("one", 1), ("ne", 5), ("nine", 9), ("eight", 8)]).unwrap();
+            let matches: Vec<Match<u8>> = matcher.matches("ninexoneight").collect();
+
+            assert_eq!(
+                matches,
+                vec![
+                    Match {
+                        value: 9,
+                        range: 0..4
+                    },
+                    Match {
+                        value: 5,
+                        range: 2..4
+                    },
+                    Match {
+                        value: 1,
+                        range: 5..8
+                    },
+                    Match {
+                        value: 5,
+                        range: 6..8
+                    },
+                    Match {
+                        value: 8,
+                        range: 7..12
+                    },
+                ]
+            );
+        }
+    }
+}
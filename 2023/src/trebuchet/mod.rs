@@ -0,0 +1,372 @@
+/*
+https://adventofcode.com/2023/day/1
+
+Something is wrong with global snow production, and you've been selected to take a look.
+The Elves have even given you a map; on it, they've used stars to mark the top fifty
+locations that are likely to be having problems.
+
+You've been doing this long enough to know that to restore snow operations, you need to
+check all fifty stars by December 25th.
+
+Collect stars by solving puzzles. Two puzzles will be made available on each day
+in the Advent calendar; the second puzzle is unlocked when you complete the first.
+Each puzzle grants one star. Good luck!
+
+You try to ask why they can't just use a weather machine ("not powerful enough") and where
+they're even sending you ("the sky") and why your map looks mostly blank ("you sure ask
+a lot of questions") and hang on did you just say the sky ("of course, where do you think
+snow comes from") when you realize that the Elves are already loading you into
+a trebuchet ("please hold still, we need to strap you in").
+
+As they're making the final adjustments, they discover that their calibration document (your
+puzzle input) has been amended by a very young Elf who was apparently just excited to
+show off her art skills. Consequently, the Elves are having trouble reading the
+values on the document.
+
+The newly-improved calibration document consists of lines of text; each line originally
+contained a specific calibration value that the Elves now need to recover.
+On each line, the calibration value can be found by combining the first digit and
+the last digit (in that order) to form a single two-digit number.
+
+For example:
+```
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+```
+
+In this example, the calibration values of these four lines are 12, 38, 15, and 77.
+Adding these together produces 142.
+
+Consider your entire calibration document. What is the sum of all of the calibration values?
+*/
+
+/*
+https://adventofcode.com/2023/day/1
+
+Your calculation isn't quite right. It looks like some of the digits are actually
+spelled out with letters: one, two, three, four, five, six, seven, eight, and nine also
+count as valid "digits".
+
+Equipped with this new information, you now need to find the real first and last digit on
+each line. For example:
+```
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+```
+
+In this example, the calibration values are 29, 83, 13, 24, 42, 14, and 76.
+Adding these together produces 281.
+
+What is the sum of all of the calibration values?
+*/
+
+pub mod bytes;
+pub mod explain;
+pub mod generator;
+pub mod matcher;
+pub mod scanner;
+pub mod vocabulary;
+
+use std::io::BufRead;
+
+use aoc_core::lines::{lines, BlankLines, Lines};
+use aoc_core::stream::{self, ReadError};
+use aoc_core::{Answer, Natural, ParseError, Part, Solution, SolveError};
+
+use vocabulary::DigitVocabulary;
+
+pub struct Trebuchet;
+
+/// One line of the calibration document.
+#[derive(Debug, PartialEq)]
+pub struct CalibrationLine {
+    /// 1-based position in the raw input.
+    pub number: usize,
+    pub text: String,
+}
+
+impl Solution for Trebuchet {
+    type Input = Vec<CalibrationLine>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        calibration_lines(lines(input, BlankLines::Error))
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer, SolveError> {
+        let (vocabulary, expected) = part_vocabulary(Part::One);
+        Ok(calibration_sum(lines, vocabulary, expected)?.into())
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Answer, SolveError> {
+        let (vocabulary, expected) = part_vocabulary(Part::Two);
+        Ok(calibration_sum(lines, vocabulary, expected)?.into())
+    }
+
+    #[cfg(feature = "parallel")]
+    fn solve(input: &str, part: Part, threads: usize) -> Result<Answer, SolveError> {
+        let (vocabulary, expected) = part_vocabulary(part);
+        let sum = aoc_core::parallel::sum_chunks(
+            input,
+            threads,
+            |chunk| calibration_lines(chunk.lines(BlankLines::Error)),
+            |lines| calibration_sum(lines, vocabulary, expected),
+        )?;
+
+        Ok(sum.into())
+    }
+
+    fn solve_reader(reader: &mut dyn BufRead, parts: &[Part]) -> Result<Vec<Answer>, ReadError> {
+        let sums = stream::sum_lines(reader, parts, BlankLines::Error, |line, parts| {
+            parts
+                .iter()
+                .map(|&part| {
+                    let (vocabulary, expected) = part_vocabulary(part);
+                    Ok(calibration_value(line.number, line.text, vocabulary, expected)?.into())
+                })
+                .collect()
+        })?;
+
+        Ok(sums.into_iter().map(Answer::from).collect())
+    }
+}
+
+/// The digits each part reads, and what a line without any lacks.
+fn part_vocabulary(part: Part) -> (&'static DigitVocabulary, &'static str) {
+    match part {
+        Part::One => (scanner::numerals(), "a digit"),
+        Part::Two => (scanner::english(), "a digit or a spelled-out digit"),
+    }
+}
+
+fn calibration_lines(lines: Lines<'_>) -> Result<Vec<CalibrationLine>, ParseError> {
+    lines
+        .map(|line| {
+            let line = line?;
+            Ok(CalibrationLine {
+                number: line.number,
+                text: line.text.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Sums the calibration values of `lines`, reading digits with `vocabulary`.
+/// A line without any digit is an error, as is non-ASCII text when the
+/// vocabulary's mode rejects it; `expected` describes what was missing.
+pub fn calibration_sum(
+    lines: &[CalibrationLine],
+    vocabulary: &DigitVocabulary,
+    expected: &str,
+) -> Result<Natural, SolveError> {
+    let mut sum = Natural::ZERO;
+
+    for line in lines {
+        let value = calibration_value(line.number, &line.text, vocabulary, expected)?;
+        sum = sum.checked_add(value)?;
+    }
+
+    Ok(sum)
+}
+
+/// The calibration value of `text`, line `number` of the document.
+fn calibration_value(
+    number: usize,
+    text: &str,
+    vocabulary: &DigitVocabulary,
+    expected: &str,
+) -> Result<usize, ParseError> {
+    if let Some((position, letter)) = vocabulary.rejected(text) {
+        let token = &text[position..position + letter.len_utf8()];
+        return Err(ParseError::at(number, text, token, "an ASCII character"));
+    }
+
+    vocabulary
+        .calibration_value(text)
+        .ok_or_else(|| ParseError::at(number, text, text, expected))
+}
+
+/// Combines the first and last digit of `line` into its calibration value.
+pub fn get_line_digits(line: &str) -> Option<usize> {
+    let (first_digit, last_digit) = scanner::first_and_last_digit(line)?;
+
+    Some(first_digit.value as usize * 10 + last_digit.value as usize)
+}
+
+/// The first digit of `input`, numeral or spelled out.
+pub fn get_first_line_digit(input: &str) -> Option<usize> {
+    scanner::first_digit(input).map(|digit| usize::from(digit.value))
+}
+
+mod test {
+    #[cfg(test)]
+    mod replace_number_letters {
+        mod basic_cases {
+            use super::super::super::get_first_line_digit;
+
+            #[test]
+            fn basic_numbers() {
+                assert_eq!(get_first_line_digit("zero"), Some(0));
+                assert_eq!(get_first_line_digit("one"), Some(1));
+                assert_eq!(get_first_line_digit("two"), Some(2));
+                assert_eq!(get_first_line_digit("three"), Some(3));
+                assert_eq!(get_first_line_digit("four"), Some(4));
+                assert_eq!(get_first_line_digit("five"), Some(5));
+                assert_eq!(get_first_line_digit("six"), Some(6));
+                assert_eq!(get_first_line_digit("seven"), Some(7));
+                assert_eq!(get_first_line_digit("eight"), Some(8));
+                assert_eq!(get_first_line_digit("nine"), Some(9));
+            }
+
+            #[test]
+            fn test_overlapping_numbers() {
+                assert_eq!(get_first_line_digit("nine"), Some(9));
+            }
+        }
+
+        mod overlapping_numbers {
+            use super::super::super::get_line_digits;
+
+            #[test]
+            fn test_overlapping_numbers() {
+                assert_eq!(get_line_digits("twone"), Some(21));
+            }
+
+            #[test]
+            fn test_overlapping_numbers_2() {
+                assert_eq!(get_line_digits("eightwo"), Some(82));
+            }
+
+            #[test]
+            fn test_overlapping_numbers_3() {
+                assert_eq!(get_line_digits("nineight"), Some(98));
+            }
+
+            #[test]
+            fn test_overlapping_numbers_4() {
+                assert_eq!(get_line_digits("eighthreee"), Some(83));
+            }
+
+            #[test]
+            fn test_overlapping_numbers_5() {
+                assert_eq!(get_line_digits("nineeight"), Some(98));
+            }
+        }
+
+        mod other_edge_cases {
+            use super::super::super::get_line_digits;
+
+            #[test]
+            fn test_other_edge_cases_1() {
+                assert_eq!(get_line_digits("eeeight"), Some(88));
+            }
+
+            #[test]
+            fn test_other_edge_cases_2() {
+                assert_eq!(get_line_digits("oooneone"), Some(11));
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod parse_errors {
+        use super::super::vocabulary::{DecoderMode, DigitVocabulary};
+        use super::super::{calibration_sum, Trebuchet};
+        use aoc_core::rng::Rng;
+        use aoc_core::{Answer, ParseError, Solution, SolveError};
+
+        #[test]
+        fn test_line_without_digit() {
+            let input = Trebuchet::parse("1abc2\npqrstuvwx").unwrap();
+            let expected = ParseError {
+                line: 2,
+                column: 1,
+                token: "pqrstuvwx".to_string(),
+                expected: "a digit".to_string(),
+            };
+
+            assert_eq!(Trebuchet::part1(&input), Err(expected.into()));
+        }
+
+        #[test]
+        fn test_non_ascii_numeric() {
+            let input = Trebuchet::parse("ab½c3").unwrap();
+
+            assert_eq!(Trebuchet::part1(&input), Ok(Answer::Int(33)));
+        }
+
+        #[test]
+        fn test_rejected_non_ascii() {
+            let input = Trebuchet::parse("1abc2\nab½c3").unwrap();
+            let vocabulary = DigitVocabulary::numerals()
+                .with_mode(DecoderMode::RejectNonAscii)
+                .unwrap();
+            let Err(SolveError::Parse(error)) = calibration_sum(&input, &vocabulary, "a digit")
+            else {
+                panic!("'½' should be rejected");
+            };
+
+            assert_eq!((error.line, error.column), (2, 3));
+            assert_eq!(error.token, "½");
+            assert_eq!(error.expected, "an ASCII character");
+        }
+
+        #[test]
+        fn test_arbitrary_documents_never_panic() {
+            let mut rng = Rng::new(1);
+            let modes = [
+                DecoderMode::Ascii,
+                DecoderMode::UnicodeDecimal,
+                DecoderMode::RejectNonAscii,
+            ];
+            let vocabularies: Vec<DigitVocabulary> = modes
+                .into_iter()
+                .map(|mode| DigitVocabulary::english().with_mode(mode).unwrap())
+                .collect();
+
+            for _ in 0..500 {
+                let document: String = (0..rng.below(40))
+                    .map(|_| loop {
+                        if let Some(c) = char::from_u32(rng.below(0x11_0000) as u32) {
+                            break *rng.pick(&[c, c, '\n', '7', '½']);
+                        }
+                    })
+                    .collect();
+                let Ok(input) = Trebuchet::parse(&document) else {
+                    continue;
+                };
+
+                let _ = Trebuchet::part1(&input);
+                let _ = Trebuchet::part2(&input);
+                for vocabulary in &vocabularies {
+                    let _ = calibration_sum(&input, vocabulary, "a digit");
+                }
+            }
+        }
+
+        #[test]
+        fn test_line_without_spelled_digit() {
+            let input = Trebuchet::parse("two1nine\r\nabcxyz\r\n").unwrap();
+            let Err(SolveError::Parse(error)) = Trebuchet::part2(&input) else {
+                panic!("line 2 has no digit");
+            };
+
+            assert_eq!((error.line, error.column), (2, 1));
+            assert_eq!(error.token, "abcxyz");
+        }
+
+        #[test]
+        fn test_blank_line() {
+            let error = Trebuchet::parse("two1nine\n\nabcone2\n").unwrap_err();
+
+            assert_eq!((error.line, error.column), (2, 1));
+            assert_eq!(error.expected, "a non-blank line");
+        }
+    }
+}
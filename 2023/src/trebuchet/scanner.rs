@@ -0,0 +1,119 @@
+//! Locating every digit in a calibration line, numerals and spelled-out
+//! words alike, with the byte range each one came from.
+
+use std::sync::OnceLock;
+
+use super::vocabulary::DigitVocabulary;
+pub use super::vocabulary::{DigitKind, DigitMatch};
+
+/// Numerals and English words, the vocabulary of part two.
+pub fn english() -> &'static DigitVocabulary {
+    static ENGLISH: OnceLock<DigitVocabulary> = OnceLock::new();
+
+    ENGLISH.get_or_init(DigitVocabulary::english)
+}
+
+/// Numerals only, the vocabulary of part one.
+pub fn numerals() -> &'static DigitVocabulary {
+    static NUMERALS: OnceLock<DigitVocabulary> = OnceLock::new();
+
+    NUMERALS.get_or_init(DigitVocabulary::numerals)
+}
+
+/// Every digit in `line`, overlapping words included ("twone" holds both a
+/// two and a one), in order of where they start.
+pub fn digit_matches(line: &str) -> Vec<DigitMatch> {
+    english().digit_matches(line)
+}
+
+/// The digit that comes first in `line`.
+pub fn first_digit(line: &str) -> Option<DigitMatch> {
+    english().first_digit(line)
+}
+
+/// The digit that comes last in `line`.
+pub fn last_digit(line: &str) -> Option<DigitMatch> {
+    english().last_digit(line)
+}
+
+/// The first and the last digit of `line`, found in a single pass.
+pub fn first_and_last_digit(line: &str) -> Option<(DigitMatch, DigitMatch)> {
+    english().first_and_last_digit(line)
+}
+
+mod test {
+    #[cfg(test)]
+    mod digit_matches {
+        use super::super::{digit_matches, DigitKind, DigitMatch};
+
+        fn spans(line: &str) -> Vec<(u8, std::ops::Range<usize>)> {
+            digit_matches(line)
+                .into_iter()
+                .map(|found| (found.value, found.range))
+                .collect()
+        }
+
+        #[test]
+        fn test_kinds_and_ranges() {
+            assert_eq!(
+                digit_matches("xtwone3four"),
+                vec![
+                    DigitMatch {
+                        value: 2,
+                        range: 1..4,
+                        kind: DigitKind::Word,
+                    },
+                    DigitMatch {
+                        value: 1,
+                        range: 3..6,
+                        kind: DigitKind::Word,
+                    },
+                    DigitMatch {
+                        value: 3,
+                        range: 6..7,
+                        kind: DigitKind::Numeral,
+                    },
+                    DigitMatch {
+                        value: 4,
+                        range: 7..11,
+                        kind: DigitKind::Word,
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn test_overlaps() {
+            assert_eq!(spans("nineight"), vec![(9, 0..4), (8, 3..8)]);
+            assert_eq!(spans("eighthreee"), vec![(8, 0..5), (3, 4..9)]);
+            assert_eq!(spans("oooneone"), vec![(1, 2..5), (1, 5..8)]);
+        }
+
+        #[test]
+        fn test_no_digits() {
+            assert_eq!(digit_matches("abcxyz"), vec![]);
+        }
+    }
+
+    #[cfg(test)]
+    mod first_and_last_digit {
+        use super::super::{first_and_last_digit, first_digit, last_digit};
+
+        #[test]
+        fn test_first_and_last() {
+            let (first, last) = first_and_last_digit("zoneight234").unwrap();
+
+            assert_eq!((first.value, first.range.clone()), (1, 1..4));
+            assert_eq!((last.value, last.range.clone()), (4, 10..11));
+            assert_eq!(first_digit("zoneight234"), Some(first));
+            assert_eq!(last_digit("zoneight234"), Some(last));
+        }
+
+        #[test]
+        fn test_single_digit() {
+            let (first, last) = first_and_last_digit("treb7uchet").unwrap();
+
+            assert_eq!(first, last);
+        }
+    }
+}
@@ -0,0 +1,595 @@
+//! Which spellings count as digits.
+//!
+//! Part one of the puzzle only knows numerals and part two adds the English
+//! words for them; other languages or house-made tokens are just further
+//! vocabularies fed to the same first/last-digit logic.
+
+use std::fmt;
+use std::ops::Range;
+
+use super::matcher::{Match, Matcher, TooLarge};
+
+pub(super) const NUMERALS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+pub(super) const ENGLISH: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const GERMAN: [&str; 10] = [
+    "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+const FRENCH: [&str; 10] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+const SPANISH: [&str; 10] = [
+    "cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+
+/// Which characters count as numerals, and what to do with non-ASCII text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DecoderMode {
+    /// Only `0` to `9` are numerals; any other character is filler.
+    #[default]
+    Ascii,
+    /// Decimal digits of every script are numerals, worth their digit value:
+    /// `٣` (Arabic-Indic three) counts as a 3. Other numeric characters, like
+    /// `½`, `²` or `Ⅻ`, are still filler.
+    UnicodeDecimal,
+    /// Like [`DecoderMode::Ascii`], but a line with any non-ASCII character is
+    /// an error.
+    RejectNonAscii,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DigitKind {
+    /// Written as a numeral, like `7`.
+    Numeral,
+    /// Spelled out, like `seven`.
+    Word,
+}
+
+/// A digit found in a line, and where.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DigitMatch {
+    pub value: u8,
+    /// Byte range of the digit within the line.
+    pub range: Range<usize>,
+    pub kind: DigitKind,
+}
+
+/// Why a vocabulary cannot be built.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VocabularyError {
+    /// The spelling stands for a value that is not a single digit, so it
+    /// would not make a two-digit calibration value.
+    NotADigit { spelling: String, value: u8 },
+    /// There are too many spellings, with their case variants and numerals,
+    /// to match at once.
+    TooLarge(TooLarge),
+}
+
+impl fmt::Display for VocabularyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VocabularyError::NotADigit { spelling, value } => {
+                write!(f, "{spelling:?} stands for {value}, not a digit")
+            }
+            VocabularyError::TooLarge(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for VocabularyError {}
+
+impl From<TooLarge> for VocabularyError {
+    fn from(error: TooLarge) -> Self {
+        VocabularyError::TooLarge(error)
+    }
+}
+
+/// A set of spellings and the digit each one stands for.
+pub struct DigitVocabulary {
+    spellings: Vec<(String, u8, DigitKind)>,
+    case_insensitive: bool,
+    mode: DecoderMode,
+    matcher: Matcher<(u8, DigitKind)>,
+}
+
+impl DigitVocabulary {
+    /// A vocabulary of spelled-out words, each standing for a digit from 0 to
+    /// 9. Numerals are not included; see [`DigitVocabulary::with_numerals`].
+    pub fn new<'a>(
+        words: impl IntoIterator<Item = (&'a str, u8)>,
+    ) -> Result<Self, VocabularyError> {
+        let spellings = words
+            .into_iter()
+            .map(|(word, value)| match value {
+                0..=9 => Ok((word.to_string(), value, DigitKind::Word)),
+                _ => Err(VocabularyError::NotADigit {
+                    spelling: word.to_string(),
+                    value,
+                }),
+            })
+            .collect::<Result<_, _>>()?;
+
+        DigitVocabulary::build(spellings, false, DecoderMode::default())
+    }
+
+    /// Only the numerals 0 to 9, as in part one of the puzzle.
+    pub fn numerals() -> Self {
+        DigitVocabulary::from_words([])
+    }
+
+    /// Numerals and English words, as in part two of the puzzle.
+    pub fn english() -> Self {
+        DigitVocabulary::from_words(ENGLISH)
+    }
+
+    /// Numerals and German words.
+    pub fn german() -> Self {
+        DigitVocabulary::from_words(GERMAN)
+    }
+
+    /// Numerals and French words.
+    pub fn french() -> Self {
+        DigitVocabulary::from_words(FRENCH)
+    }
+
+    /// Numerals and Spanish words.
+    pub fn spanish() -> Self {
+        DigitVocabulary::from_words(SPANISH)
+    }
+
+    /// Adds the numerals 0 to 9.
+    pub fn with_numerals(mut self) -> Result<Self, VocabularyError> {
+        for (value, numeral) in NUMERALS.into_iter().enumerate() {
+            self.spellings
+                .push((numeral.to_string(), value as u8, DigitKind::Numeral));
+        }
+
+        DigitVocabulary::build(self.spellings, self.case_insensitive, self.mode)
+    }
+
+    /// Makes words match regardless of case.
+    ///
+    /// ASCII letters may be in any case. Other letters match when the word is
+    /// written all lowercase, all uppercase or capitalized ("fünf", "FÜNF",
+    /// "Fünf").
+    pub fn case_insensitive(self) -> Result<Self, VocabularyError> {
+        DigitVocabulary::build(self.spellings, true, self.mode)
+    }
+
+    /// Switches to `mode`; see [`DecoderMode`]. The default is
+    /// [`DecoderMode::Ascii`].
+    pub fn with_mode(self, mode: DecoderMode) -> Result<Self, VocabularyError> {
+        DigitVocabulary::build(self.spellings, self.case_insensitive, mode)
+    }
+
+    pub fn mode(&self) -> DecoderMode {
+        self.mode
+    }
+
+    /// The first non-ASCII character of `line` and its byte offset, if the
+    /// mode rejects it.
+    pub fn rejected(&self, line: &str) -> Option<(usize, char)> {
+        match self.mode {
+            DecoderMode::RejectNonAscii => line.char_indices().find(|(_, c)| !c.is_ascii()),
+            DecoderMode::Ascii | DecoderMode::UnicodeDecimal => None,
+        }
+    }
+
+    /// Every digit in `line`, overlapping words included ("twone" holds both a
+    /// two and a one), in order of where they start.
+    pub fn digit_matches(&self, line: &str) -> Vec<DigitMatch> {
+        let mut matches: Vec<DigitMatch> = self.matcher.matches(line).map(digit_match).collect();
+        matches.sort_by_key(|found| (found.range.start, found.range.end));
+
+        matches
+    }
+
+    /// The digit that comes first in `line`.
+    pub fn first_digit(&self, line: &str) -> Option<DigitMatch> {
+        self.matcher.first(line).map(digit_match)
+    }
+
+    /// The digit that comes last in `line`.
+    pub fn last_digit(&self, line: &str) -> Option<DigitMatch> {
+        self.first_and_last_digit(line).map(|(_, last)| last)
+    }
+
+    /// The first and the last digit of `line`, found in a single pass.
+    pub fn first_and_last_digit(&self, line: &str) -> Option<(DigitMatch, DigitMatch)> {
+        let (first, last) = self.matcher.first_last(line)?;
+
+        Some((digit_match(first), digit_match(last)))
+    }
+
+    /// The two-digit number made of the first and last digit of `line`.
+    pub fn calibration_value(&self, line: &str) -> Option<usize> {
+        let (first, last) = self.matcher.first_last(line)?;
+
+        Some(usize::from(first.value.0) * 10 + usize::from(last.value.0))
+    }
+
+    /// A preset: numerals and `words`, which are fixed and known to fit.
+    fn from_words<const N: usize>(words: [&str; N]) -> Self {
+        DigitVocabulary::new(words.into_iter().zip(0..))
+            .and_then(DigitVocabulary::with_numerals)
+            .expect("preset vocabularies are valid")
+    }
+
+    fn build(
+        spellings: Vec<(String, u8, DigitKind)>,
+        case_insensitive: bool,
+        mode: DecoderMode,
+    ) -> Result<Self, VocabularyError> {
+        // Other scripts' numerals only join a vocabulary that has numerals.
+        let has_numerals = spellings
+            .iter()
+            .any(|(_, _, kind)| *kind == DigitKind::Numeral);
+        let decimals: Vec<(String, u8, DigitKind)> = match mode {
+            DecoderMode::UnicodeDecimal if has_numerals => unicode_decimals().collect(),
+            _ => Vec::new(),
+        };
+        let all = spellings.iter().chain(&decimals);
+
+        let matcher = if case_insensitive {
+            let variants = all.flat_map(|(spelling, value, kind)| {
+                case_variants(spelling)
+                    .into_iter()
+                    .map(move |variant| (variant, (*value, *kind)))
+            });
+            let variants: Vec<(String, (u8, DigitKind))> = variants.collect();
+
+            Matcher::new_ascii_case_insensitive(
+                variants
+                    .iter()
+                    .map(|(variant, value)| (variant.as_str(), *value)),
+            )?
+        } else {
+            Matcher::new(all.map(|(spelling, value, kind)| (spelling.as_str(), (*value, *kind))))?
+        };
+
+        Ok(DigitVocabulary {
+            spellings,
+            case_insensitive,
+            mode,
+            matcher,
+        })
+    }
+}
+
+/// The zero of every run of ten Unicode decimal digits (general category Nd)
+/// other than ASCII, as of Unicode 17. Each run holds the digits 0 to 9 in
+/// order.
+const DECIMAL_ZEROS: [u32; 76] = [
+    0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6, 0x0D66,
+    0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80, 0x1A90,
+    0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0, 0xFF10,
+    0x104A0, 0x10D30, 0x10D40, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0,
+    0x11650, 0x116C0, 0x116D0, 0x116DA, 0x11730, 0x118E0, 0x11950, 0x11BF0, 0x11C50, 0x11D50,
+    0x11DA0, 0x11DE0, 0x11F50, 0x16130, 0x16A60, 0x16AC0, 0x16B50, 0x16D70, 0x1CCF0, 0x1D7CE,
+    0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0, 0x1E5F1, 0x1E950, 0x1FBF0,
+];
+
+/// Every non-ASCII decimal digit, as a numeral spelling.
+fn unicode_decimals() -> impl Iterator<Item = (String, u8, DigitKind)> {
+    DECIMAL_ZEROS.into_iter().flat_map(|zero| {
+        (0..10u8).map(move |value| {
+            let digit = char::from_u32(zero + u32::from(value)).expect("decimal digits are chars");
+            (digit.to_string(), value, DigitKind::Numeral)
+        })
+    })
+}
+
+/// The lowercase, uppercase and capitalized forms of `word`; together with
+/// ASCII case folding these cover the usual ways of writing it.
+fn case_variants(word: &str) -> Vec<String> {
+    let lower = word.to_lowercase();
+    let mut chars = lower.chars();
+    let capitalized = chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default();
+
+    vec![lower, word.to_uppercase(), capitalized]
+}
+
+fn digit_match(found: Match<(u8, DigitKind)>) -> DigitMatch {
+    let (value, kind) = found.value;
+
+    DigitMatch {
+        value,
+        range: found.range,
+        kind,
+    }
+}
+
+mod test {
+    #[cfg(test)]
+    mod presets {
+        use super::super::DigitVocabulary;
+
+        #[test]
+        fn test_numerals_ignore_words() {
+            let numerals = DigitVocabulary::numerals();
+
+            assert_eq!(numerals.calibration_value("two1nine"), Some(11));
+            assert_eq!(numerals.calibration_value("eightwothree"), None);
+        }
+
+        #[test]
+        fn test_english() {
+            let english = DigitVocabulary::english();
+
+            assert_eq!(english.calibration_value("two1nine"), Some(29));
+            assert_eq!(english.calibration_value("xtwone3four"), Some(24));
+        }
+
+        #[test]
+        fn test_other_languages() {
+            assert_eq!(
+                DigitVocabulary::german().calibration_value("xfünfzehnachtzig"),
+                Some(58)
+            );
+            assert_eq!(
+                DigitVocabulary::french().calibration_value("trois7zéro"),
+                Some(30)
+            );
+            assert_eq!(
+                DigitVocabulary::spanish().calibration_value("nuevecientosdos"),
+                Some(92)
+            );
+        }
+
+        #[test]
+        fn test_german_overlaps() {
+            let german = DigitVocabulary::german();
+
+            assert_eq!(german.calibration_value("zweins"), Some(21));
+            assert_eq!(german.calibration_value("dreins"), Some(31));
+            assert_eq!(german.last_digit("xdreinsx").unwrap().range, 3..7);
+        }
+    }
+
+    #[cfg(test)]
+    mod custom {
+        use super::super::{DigitKind, DigitVocabulary, VocabularyError};
+
+        #[test]
+        fn test_custom_words() {
+            let vocabulary = DigitVocabulary::new([("uno", 1), ("dos", 2), ("ichi", 1)]).unwrap();
+
+            assert_eq!(vocabulary.calibration_value("ichi-dos"), Some(12));
+            assert_eq!(vocabulary.calibration_value("3 uno 4"), Some(11));
+            assert_eq!(vocabulary.first_digit("x7").map(|found| found.kind), None);
+        }
+
+        #[test]
+        fn test_inner_words() {
+            let vocabulary = DigitVocabulary::new([("abcd", 1), ("bc", 2)]).unwrap();
+
+            assert_eq!(vocabulary.first_digit("abcd").unwrap().value, 1);
+            assert_eq!(vocabulary.calibration_value("abcd"), Some(12));
+        }
+
+        #[test]
+        fn test_value_above_nine() {
+            let error = DigitVocabulary::new([("one", 1), ("ten", 10)]).err();
+
+            assert_eq!(
+                error,
+                Some(VocabularyError::NotADigit {
+                    spelling: "ten".to_string(),
+                    value: 10,
+                })
+            );
+            assert_eq!(
+                error.unwrap().to_string(),
+                "\"ten\" stands for 10, not a digit"
+            );
+        }
+
+        #[test]
+        fn test_too_many_spellings() {
+            // "é" and "É" do not fold like ASCII, so case variants double
+            // the states.
+            let words: Vec<String> = (0..40_000).map(|number| format!("é{number}")).collect();
+            let vocabulary =
+                DigitVocabulary::new(words.iter().map(|word| (word.as_str(), 1))).unwrap();
+
+            assert!(matches!(
+                vocabulary.case_insensitive(),
+                Err(VocabularyError::TooLarge(_))
+            ));
+        }
+
+        #[test]
+        fn test_with_numerals() {
+            let vocabulary = DigitVocabulary::new([("ichi", 1)])
+                .unwrap()
+                .with_numerals()
+                .unwrap();
+            let last = vocabulary.last_digit("ichi 7").unwrap();
+
+            assert_eq!(vocabulary.calibration_value("ichi 7"), Some(17));
+            assert_eq!((last.value, last.kind), (7, DigitKind::Numeral));
+        }
+
+        #[test]
+        fn test_case_insensitive() {
+            let vocabulary = DigitVocabulary::german().case_insensitive().unwrap();
+
+            assert_eq!(vocabulary.calibration_value("EINS-Zwei"), Some(12));
+            assert_eq!(vocabulary.calibration_value("FÜNF und Fünf"), Some(55));
+            assert_eq!(vocabulary.calibration_value("dReI"), Some(33));
+            assert_eq!(DigitVocabulary::german().calibration_value("DREI"), None);
+        }
+
+        #[test]
+        fn test_case_insensitive_keeps_ranges() {
+            let vocabulary = DigitVocabulary::english().case_insensitive().unwrap();
+            let matches = vocabulary.digit_matches("xTWOne");
+
+            assert_eq!(matches[0].range, 1..4);
+            assert_eq!(matches[1].range, 3..6);
+        }
+    }
+
+    #[cfg(test)]
+    mod modes {
+        use super::super::{DecoderMode, DigitVocabulary, DECIMAL_ZEROS};
+
+        #[test]
+        fn test_ascii_skips_other_numerics() {
+            let numerals = DigitVocabulary::numerals();
+
+            assert_eq!(numerals.calibration_value("½x²7Ⅻ٣"), Some(77));
+            assert_eq!(numerals.rejected("½x²7Ⅻ٣"), None);
+        }
+
+        #[test]
+        fn test_unicode_decimal() {
+            let numerals = DigitVocabulary::numerals()
+                .with_mode(DecoderMode::UnicodeDecimal)
+                .unwrap();
+
+            assert_eq!(numerals.calibration_value("٣x²7Ⅻ"), Some(37));
+            assert_eq!(numerals.calibration_value("a４b𝟡c"), Some(49));
+            assert_eq!(numerals.calibration_value("½²Ⅻ"), None);
+            assert_eq!(numerals.calibration_value("\u{10D41}x\u{1E4F9}"), Some(19));
+        }
+
+        #[test]
+        fn test_decimal_zeros() {
+            for pair in DECIMAL_ZEROS.windows(2) {
+                assert!(
+                    pair[0] + 10 <= pair[1],
+                    "{:#X} overlaps {:#X}",
+                    pair[0],
+                    pair[1]
+                );
+            }
+            for zero in DECIMAL_ZEROS {
+                for code in zero..=zero + 9 {
+                    let digit = char::from_u32(code).unwrap();
+                    assert!(digit.is_numeric(), "{code:#X} is not numeric");
+                }
+            }
+        }
+
+        #[test]
+        fn test_unicode_decimal_needs_numerals() {
+            let words = DigitVocabulary::new([("one", 1)])
+                .unwrap()
+                .with_mode(DecoderMode::UnicodeDecimal)
+                .unwrap();
+
+            assert_eq!(words.calibration_value("٣one"), Some(11));
+        }
+
+        #[test]
+        fn test_mode_survives_rebuilds() {
+            let vocabulary = DigitVocabulary::new([("one", 1)])
+                .and_then(|vocabulary| vocabulary.with_mode(DecoderMode::UnicodeDecimal))
+                .and_then(DigitVocabulary::with_numerals)
+                .and_then(DigitVocabulary::case_insensitive)
+                .unwrap();
+
+            assert_eq!(vocabulary.mode(), DecoderMode::UnicodeDecimal);
+            assert_eq!(vocabulary.calibration_value("ONE٣"), Some(13));
+        }
+
+        #[test]
+        fn test_reject_non_ascii() {
+            let numerals = DigitVocabulary::numerals()
+                .with_mode(DecoderMode::RejectNonAscii)
+                .unwrap();
+
+            assert_eq!(numerals.rejected("ab½c3"), Some((2, '½')));
+            assert_eq!(numerals.rejected("abc3"), None);
+        }
+    }
+
+    #[cfg(test)]
+    mod properties {
+        use aoc_core::rng::Rng;
+
+        use super::super::{DecoderMode, DigitKind, DigitVocabulary};
+
+        /// Characters worth mixing in: ASCII, numerals of other scripts,
+        /// other numerics, and multi-byte letters that share lead bytes with
+        /// the digits.
+        const INTERESTING: [char; 14] = [
+            '½', '²', 'Ⅻ', '٣', '۴', '४', '４', '𝟡', 'ü', 'é', 'Ω', '中', '😀', '\u{0}',
+        ];
+
+        fn arbitrary_string(rng: &mut Rng) -> String {
+            let len = rng.below(24);
+            (0..len)
+                .map(|_| match rng.below(4) {
+                    0 => char::from(rng.below(0x80) as u8),
+                    1 => *rng.pick(&INTERESTING),
+                    2 => *rng.pick(&['o', 'n', 'e', 't', 'w', '1', '9']),
+                    _ => loop {
+                        if let Some(c) = char::from_u32(rng.below(0x11_0000) as u32) {
+                            break c;
+                        }
+                    },
+                })
+                .collect()
+        }
+
+        /// The calibration value from ASCII numerals alone, the obvious way.
+        fn ascii_value(line: &str) -> Option<usize> {
+            let mut digits = line
+                .chars()
+                .filter(char::is_ascii_digit)
+                .filter_map(|c| c.to_digit(10));
+            let first = digits.next()?;
+            let last = digits.next_back().unwrap_or(first);
+
+            Some((first * 10 + last) as usize)
+        }
+
+        #[test]
+        fn test_modes_agree_on_arbitrary_strings() {
+            let ascii = DigitVocabulary::numerals();
+            let unicode = DigitVocabulary::numerals()
+                .with_mode(DecoderMode::UnicodeDecimal)
+                .unwrap();
+            let reject = DigitVocabulary::numerals()
+                .with_mode(DecoderMode::RejectNonAscii)
+                .unwrap();
+            let english = DigitVocabulary::english()
+                .with_mode(DecoderMode::UnicodeDecimal)
+                .unwrap();
+            let mut rng = Rng::new(2023);
+
+            for _ in 0..5_000 {
+                let line = arbitrary_string(&mut rng);
+                let expected = ascii_value(&line);
+
+                assert_eq!(ascii.calibration_value(&line), expected, "{line:?}");
+                assert_eq!(reject.calibration_value(&line), expected, "{line:?}");
+                assert_eq!(
+                    reject.rejected(&line).is_some(),
+                    !line.is_ascii(),
+                    "{line:?}"
+                );
+                if expected.is_some() {
+                    assert!(unicode.calibration_value(&line).is_some(), "{line:?}");
+                }
+                if let Some(value) = unicode.calibration_value(&line) {
+                    assert!(value < 100, "{line:?}");
+                }
+
+                for found in english.digit_matches(&line) {
+                    let text = &line[found.range.clone()];
+                    assert!(found.value < 10, "{line:?}");
+                    if found.kind == DigitKind::Numeral {
+                        assert_eq!(text.chars().count(), 1, "{line:?}");
+                    }
+                }
+            }
+        }
+    }
+}
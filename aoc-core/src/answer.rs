@@ -0,0 +1,244 @@
+//! What a part of a puzzle comes out to, and adding up to it safely.
+//!
+//! Answers are summed from many lines, and generated stress inputs can make
+//! those sums outgrow 64 bits. [`Natural`] never wraps: it either reports
+//! [`Overflow`] or, with the `bigint` feature, carries on with a [`BigUint`].
+//!
+//! [`BigUint`]: crate::bigint::BigUint
+
+use std::fmt;
+
+#[cfg(feature = "bigint")]
+use crate::bigint::BigUint;
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(u64),
+    #[cfg(feature = "bigint")]
+    Big(BigUint),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{value}"),
+            #[cfg(feature = "bigint")]
+            Answer::Big(value) => write!(f, "{value}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Int(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<Natural> for Answer {
+    fn from(value: Natural) -> Self {
+        match value {
+            Natural::Small(value) => Answer::Int(value),
+            #[cfg(feature = "bigint")]
+            Natural::Big(value) => Answer::Big(value),
+        }
+    }
+}
+
+/// An arithmetic result too large for 64 bits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the answer does not fit in 64 bits; build with the bigint feature to compute it"
+        )
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// A non-negative integer that is added and multiplied without wrapping.
+///
+/// Values that fit in a `u64` are always `Small`, so equal numbers compare
+/// equal whatever path produced them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Natural {
+    Small(u64),
+    #[cfg(feature = "bigint")]
+    Big(BigUint),
+}
+
+impl Natural {
+    pub const ZERO: Natural = Natural::Small(0);
+    pub const ONE: Natural = Natural::Small(1);
+
+    pub fn checked_add(self, other: impl Into<Natural>) -> Result<Natural, Overflow> {
+        match (self, other.into()) {
+            (Natural::Small(a), Natural::Small(b)) => match a.checked_add(b) {
+                Some(sum) => Ok(Natural::Small(sum)),
+                None => Natural::promoted(a, b, Operation::Add),
+            },
+            #[cfg(feature = "bigint")]
+            (a, b) => Ok(Natural::from_big(a.to_big().add(&b.to_big()))),
+        }
+    }
+
+    pub fn checked_mul(self, other: impl Into<Natural>) -> Result<Natural, Overflow> {
+        match (self, other.into()) {
+            (Natural::Small(a), Natural::Small(b)) => match a.checked_mul(b) {
+                Some(product) => Ok(Natural::Small(product)),
+                None => Natural::promoted(a, b, Operation::Mul),
+            },
+            #[cfg(feature = "bigint")]
+            (a, b) => Ok(Natural::from_big(a.to_big().mul(&b.to_big()))),
+        }
+    }
+
+    /// Adds up `values`, stopping at the first overflow.
+    pub fn sum<T: Into<Natural>>(values: impl IntoIterator<Item = T>) -> Result<Natural, Overflow> {
+        values
+            .into_iter()
+            .try_fold(Natural::ZERO, Natural::checked_add)
+    }
+
+    /// Multiplies `values` together, stopping at the first overflow.
+    pub fn product<T: Into<Natural>>(
+        values: impl IntoIterator<Item = T>,
+    ) -> Result<Natural, Overflow> {
+        values
+            .into_iter()
+            .try_fold(Natural::ONE, Natural::checked_mul)
+    }
+
+    /// The result of `operation` on two `u64`s that overflowed it.
+    #[cfg(feature = "bigint")]
+    fn promoted(a: u64, b: u64, operation: Operation) -> Result<Natural, Overflow> {
+        let (a, b) = (BigUint::from(a), BigUint::from(b));
+
+        Ok(Natural::from_big(match operation {
+            Operation::Add => a.add(&b),
+            Operation::Mul => a.mul(&b),
+        }))
+    }
+
+    #[cfg(not(feature = "bigint"))]
+    fn promoted(_: u64, _: u64, _: Operation) -> Result<Natural, Overflow> {
+        Err(Overflow)
+    }
+
+    #[cfg(feature = "bigint")]
+    fn to_big(&self) -> BigUint {
+        match self {
+            Natural::Small(value) => BigUint::from(*value),
+            Natural::Big(value) => value.clone(),
+        }
+    }
+
+    #[cfg(feature = "bigint")]
+    fn from_big(value: BigUint) -> Natural {
+        match value.to_u64() {
+            Some(value) => Natural::Small(value),
+            None => Natural::Big(value),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Add,
+    Mul,
+}
+
+impl fmt::Display for Natural {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Answer::from(self.clone()).fmt(f)
+    }
+}
+
+impl From<u64> for Natural {
+    fn from(value: u64) -> Self {
+        Natural::Small(value)
+    }
+}
+
+impl From<usize> for Natural {
+    fn from(value: usize) -> Self {
+        Natural::Small(value as u64)
+    }
+}
+
+impl From<u8> for Natural {
+    fn from(value: u8) -> Self {
+        Natural::Small(u64::from(value))
+    }
+}
+
+mod test {
+    #[cfg(test)]
+    mod natural {
+        use super::super::{Answer, Natural, Overflow};
+
+        #[test]
+        fn test_small_arithmetic() {
+            assert_eq!(Natural::sum([1u64, 2, 3]), Ok(Natural::Small(6)));
+            assert_eq!(Natural::product([2u64, 3, 4]), Ok(Natural::Small(24)));
+            assert_eq!(Natural::sum(Vec::<u64>::new()), Ok(Natural::ZERO));
+            assert_eq!(Answer::from(Natural::Small(42)).to_string(), "42");
+        }
+
+        #[test]
+        fn test_answers() {
+            assert_eq!(Answer::from(7usize), Answer::Int(7));
+            assert_eq!(Answer::from("abc").to_string(), "abc");
+            assert_eq!(
+                Overflow.to_string(),
+                "the answer does not fit in 64 bits; build with the bigint feature to compute it"
+            );
+        }
+
+        #[cfg(not(feature = "bigint"))]
+        #[test]
+        fn test_overflow() {
+            assert_eq!(Natural::sum([u64::MAX, 1]), Err(Overflow));
+            assert_eq!(Natural::product([u64::MAX, 2]), Err(Overflow));
+        }
+
+        #[cfg(feature = "bigint")]
+        #[test]
+        fn test_big() {
+            let sum = Natural::sum([u64::MAX, 1]).unwrap();
+            let product = Natural::product([u64::MAX, u64::MAX, 0]).unwrap();
+
+            assert_eq!(sum.to_string(), "18446744073709551616");
+            assert!(matches!(sum, Natural::Big(_)));
+            assert_eq!(product, Natural::ZERO);
+            assert_eq!(
+                Natural::sum([sum, Natural::Small(1)]).unwrap().to_string(),
+                "18446744073709551617"
+            );
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Just enough of an arbitrary-precision unsigned integer to add up and
+//! multiply puzzle answers that outgrow 64 bits.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+/// An unsigned integer of any size.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// Base 2^32 digits, least significant first, without leading zeros.
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        BigUint { limbs: Vec::new() }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// The value as a `u64`, if it fits.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs[..] {
+            [] => Some(0),
+            [low] => Some(u64::from(low)),
+            [low, high] => Some(u64::from(high) << 32 | u64::from(low)),
+            _ => None,
+        }
+    }
+
+    pub fn add(&self, other: &BigUint) -> BigUint {
+        let (long, short) = match self.limbs.len() >= other.limbs.len() {
+            true => (&self.limbs, &other.limbs),
+            false => (&other.limbs, &self.limbs),
+        };
+        let mut limbs = Vec::with_capacity(long.len() + 1);
+        let mut carry = 0u64;
+        for (index, &limb) in long.iter().enumerate() {
+            let sum = u64::from(limb) + u64::from(short.get(index).copied().unwrap_or(0)) + carry;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        if carry != 0 {
+            limbs.push(carry as u32);
+        }
+
+        BigUint { limbs }
+    }
+
+    pub fn mul(&self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::zero();
+        }
+
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let product = u64::from(a) * u64::from(b) + u64::from(limbs[i + j]) + carry;
+                limbs[i + j] = product as u32;
+                carry = product >> 32;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+
+        BigUint::normalized(limbs)
+    }
+
+    /// Divides in place by a small `divisor`, returning the remainder.
+    fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        let mut remainder = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let current = remainder << 32 | u64::from(*limb);
+            *limb = (current / u64::from(divisor)) as u32;
+            remainder = current % u64::from(divisor);
+        }
+        *self = BigUint::normalized(std::mem::take(&mut self.limbs));
+
+        remainder as u32
+    }
+
+    fn normalized(mut limbs: Vec<u32>) -> BigUint {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+
+        BigUint { limbs }
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        BigUint::normalized(vec![value as u32, (value >> 32) as u32])
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Peel off nine decimal digits at a time, least significant first.
+        const CHUNK: u32 = 1_000_000_000;
+        let mut rest = self.clone();
+        let mut chunks = Vec::new();
+        loop {
+            chunks.push(rest.div_rem_small(CHUNK));
+            if rest.is_zero() {
+                break;
+            }
+        }
+
+        let mut digits = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{chunk:09}"));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+mod test {
+    #[cfg(test)]
+    mod big_uint {
+        use super::super::BigUint;
+
+        fn big(value: u64) -> BigUint {
+            BigUint::from(value)
+        }
+
+        #[test]
+        fn test_display() {
+            assert_eq!(big(0).to_string(), "0");
+            assert_eq!(big(1_000_000_007).to_string(), "1000000007");
+            assert_eq!(big(u64::MAX).to_string(), "18446744073709551615");
+        }
+
+        #[test]
+        fn test_add_carries() {
+            let sum = big(u64::MAX).add(&big(1));
+
+            assert_eq!(sum.to_string(), "18446744073709551616");
+            assert_eq!(sum.to_u64(), None);
+            assert_eq!(big(2).add(&big(3)).to_u64(), Some(5));
+        }
+
+        #[test]
+        fn test_mul() {
+            let square = big(u64::MAX).mul(&big(u64::MAX));
+
+            assert_eq!(
+                square.to_string(),
+                "340282366920938463426481119284349108225"
+            );
+            assert_eq!(square.mul(&big(0)), big(0));
+            assert_eq!(big(0).to_u64(), Some(0));
+        }
+
+        #[test]
+        fn test_order() {
+            let huge = big(u64::MAX).add(&big(1));
+
+            assert!(huge > big(u64::MAX));
+            assert!(big(3) < big(4));
+            assert_eq!(big(7).cmp(&big(7)), std::cmp::Ordering::Equal);
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::examples::{self, Example};
+use crate::Solver;
+
+/// A registry entry tying a puzzle date to the code that solves it.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub solver: &'static dyn Solver,
+    /// The solution's source file, whose header carries the puzzle text.
+    pub source: &'static str,
+}
+
+impl Day {
+    /// The worked examples stated in the puzzle text.
+    pub fn examples(&self) -> Vec<Example> {
+        examples::extract(self.source)
+    }
+
+    /// The puzzle page linked at the top of the header, such as
+    /// `https://adventofcode.com/2023/day/1`.
+    pub fn url(&self) -> Option<&'static str> {
+        let header = self.source.trim_start().strip_prefix("/*")?;
+        let header = &header[..header.find("*/")?];
+
+        header
+            .lines()
+            .map(str::trim)
+            .find(|line| line.starts_with("https://adventofcode.com/"))
+    }
+}
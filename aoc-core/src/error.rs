@@ -0,0 +1,160 @@
+use std::fmt;
+
+use crate::Overflow;
+
+/// A problem in the puzzle input, located by 1-based line and byte column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The offending text; empty when the problem is a missing token.
+    pub token: String,
+    /// What the parser wanted to find at this position.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Builds an error for `token`, which must be a slice of `line`.
+    ///
+    /// The column is recovered from where `token` sits inside `line`, so parsers
+    /// can keep working with plain sub-slices instead of tracking offsets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `token` is not part of `line`.
+    pub fn at(line_number: usize, line: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|offset| offset + token.len() <= line.len())
+            .expect("token is not part of line");
+
+        ParseError {
+            line: line_number,
+            column: offset + 1,
+            token: token.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Shows the offending line of `source` with a caret under the bad token.
+    pub fn snippet(&self, source: &str) -> String {
+        match source.split('\n').nth(self.line - 1) {
+            Some(text) => self.line_snippet(text),
+            None => String::new(),
+        }
+    }
+
+    /// Like [`ParseError::snippet`], given only the offending line's `text`.
+    pub fn line_snippet(&self, text: &str) -> String {
+        let text = text.trim_end_matches('\r');
+
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let padding = " ".repeat(text.get(..self.column - 1).map_or(0, |s| s.chars().count()));
+        let carets = "^".repeat(self.token.chars().count().max(1));
+
+        format!("{gutter} |\n{number} | {text}\n{gutter} | {padding}{carets}")
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+
+        if self.token.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found {:?}", self.token)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Why a part could not be solved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    Overflow(Overflow),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(error) => error.fmt(f),
+            SolveError::Overflow(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SolveError::Parse(error) => Some(error),
+            SolveError::Overflow(error) => Some(error),
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> Self {
+        SolveError::Parse(error)
+    }
+}
+
+impl From<Overflow> for SolveError {
+    fn from(error: Overflow) -> Self {
+        SolveError::Overflow(error)
+    }
+}
+
+mod test {
+    #[cfg(test)]
+    mod snippet {
+        use super::super::ParseError;
+
+        #[test]
+        fn test_caret_under_token() {
+            let source = "Game 1: 3 blue\nGame x: 4 red";
+            let line = source.split('\n').nth(1).unwrap();
+            let error = ParseError::at(2, line, &line[5..6], "a game id");
+
+            assert_eq!(error.column, 6);
+            assert_eq!(
+                error.to_string(),
+                r#"line 2, column 6: expected a game id, found "x""#
+            );
+            assert_eq!(error.snippet(source), "  |\n2 | Game x: 4 red\n  |      ^");
+        }
+
+        #[test]
+        fn test_missing_token() {
+            let line = "Game 1";
+            let error = ParseError::at(1, line, &line[6..], "':'");
+
+            assert_eq!(
+                error.to_string(),
+                "line 1, column 7: expected ':', found nothing"
+            );
+            assert_eq!(error.snippet(line), "  |\n1 | Game 1\n  |       ^");
+        }
+
+        #[test]
+        #[should_panic(expected = "token is not part of line")]
+        fn test_token_before_line() {
+            let source = "Game 1: 3 blue";
+            ParseError::at(1, &source[5..], &source[..4], "a game id");
+        }
+
+        #[test]
+        #[should_panic(expected = "token is not part of line")]
+        fn test_token_after_line() {
+            let source = "Game 1: 3 blue";
+            ParseError::at(1, &source[..4], &source[3..6], "a game id");
+        }
+    }
+}
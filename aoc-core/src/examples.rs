@@ -0,0 +1,177 @@
+//! Pulls worked examples out of the puzzle text kept at the top of each
+//! solution file.
+//!
+//! A solution file opens with one block comment per part, each holding the
+//! puzzle prose as published. The prose contains a fenced example input and a
+//! sentence announcing its answer ("Adding these together produces 142.",
+//! "you get 8."), which is all a test needs.
+
+use crate::Part;
+
+/// Phrases that introduce the answer to the example, most specific first.
+const ANSWER_MARKERS: [&str; 3] = ["produces the sum", "produces", "you get"];
+
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub part: Part,
+    pub input: String,
+    pub answer: String,
+}
+
+/// Returns the examples found in the leading block comments of `source`.
+///
+/// The first block comment describes part one and the second part two; a
+/// block without both a fenced input and an answer sentence is skipped.
+pub fn extract(source: &str) -> Vec<Example> {
+    header_blocks(source)
+        .into_iter()
+        .zip(Part::ALL)
+        .filter_map(|(block, part)| {
+            let (input, after) = fenced_input(block)?;
+            let answer = stated_answer(after)?;
+
+            Some(Example {
+                part,
+                input,
+                answer,
+            })
+        })
+        .collect()
+}
+
+fn header_blocks(source: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut rest = source.trim_start();
+
+    while let Some(body) = rest.strip_prefix("/*") {
+        let Some(end) = body.find("*/") else {
+            break;
+        };
+        blocks.push(&body[..end]);
+        rest = body[end + 2..].trim_start();
+    }
+
+    blocks
+}
+
+fn is_fence(line: &str) -> bool {
+    let line = line.trim();
+    line.len() >= 3 && line.chars().all(|c| c == '`')
+}
+
+/// Returns the first fenced block and the text following its closing fence.
+fn fenced_input(block: &str) -> Option<(String, &str)> {
+    let mut lines = Vec::new();
+    let mut inside = false;
+    let mut offset = 0;
+
+    for line in block.split_inclusive('\n') {
+        offset += line.len();
+
+        if is_fence(line) {
+            if inside {
+                return Some((lines.join("\n"), &block[offset..]));
+            }
+            inside = true;
+        } else if inside {
+            lines.push(line.trim_end_matches(['\n', '\r']));
+        }
+    }
+
+    None
+}
+
+fn stated_answer(text: &str) -> Option<String> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    ANSWER_MARKERS
+        .iter()
+        .filter_map(|marker| {
+            let start = text.rfind(marker)? + marker.len();
+            let answer: String = text[start..]
+                .trim_start()
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+
+            (!answer.is_empty()).then_some((start, answer))
+        })
+        .max_by_key(|(start, _)| *start)
+        .map(|(_, answer)| answer)
+}
+
+mod test {
+    #[cfg(test)]
+    mod extract {
+        use super::super::{extract, Example};
+        use crate::Part;
+
+        const SOURCE: &str = "/*
+https://adventofcode.com/2023/day/1
+
+For example:
+```
+1abc2
+treb7uchet
+```
+
+In this example, the calibration values of these lines are 12 and 77.
+Adding these together produces 89.
+
+Consider your entire calibration document.
+*/
+
+/*
+Again consider the example games from earlier:
+````
+Game 1: 3 blue
+````
+
+Adding up these five powers produces the
+sum 2286.
+*/
+
+pub struct Day;
+";
+
+        #[test]
+        fn test_both_parts() {
+            assert_eq!(
+                extract(SOURCE),
+                vec![
+                    Example {
+                        part: Part::One,
+                        input: "1abc2\ntreb7uchet".to_string(),
+                        answer: "89".to_string(),
+                    },
+                    Example {
+                        part: Part::Two,
+                        input: "Game 1: 3 blue".to_string(),
+                        answer: "2286".to_string(),
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn test_you_get() {
+            let source = "/*\n```\nGame 1\n```\nIf you add up the IDs, you get 8.\n\
+                Only 12 red cubes, 13 green cubes, and 14 blue cubes.\n*/";
+
+            assert_eq!(extract(source)[0].answer, "8");
+        }
+
+        #[test]
+        fn test_no_header() {
+            assert_eq!(
+                extract("pub struct Day;\n/*\n```\n1\n```\nproduces 1\n*/"),
+                vec![]
+            );
+        }
+
+        #[test]
+        fn test_missing_answer() {
+            assert_eq!(extract("/*\n```\n1abc2\n```\nNo answer here.\n*/"), vec![]);
+        }
+    }
+}
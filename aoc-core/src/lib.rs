@@ -0,0 +1,19 @@
+//! Shared building blocks for the per-year Advent of Code crates.
+
+pub mod answer;
+#[cfg(feature = "bigint")]
+pub mod bigint;
+mod day;
+mod error;
+pub mod examples;
+pub mod lines;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod rng;
+mod solution;
+pub mod stream;
+
+pub use answer::{Answer, Natural, Overflow};
+pub use day::Day;
+pub use error::{ParseError, SolveError};
+pub use solution::{Part, Solution, Solver};
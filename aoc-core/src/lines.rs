@@ -0,0 +1,268 @@
+//! The one way every day splits its puzzle input into lines.
+//!
+//! Puzzle inputs end with a newline and are sometimes saved with Windows line
+//! endings; neither should be visible to a solution. Line numbers always refer
+//! to the raw input so errors point at the right place.
+
+use crate::ParseError;
+
+/// What to do with an empty line in the middle of the input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlankLines {
+    Skip,
+    Error,
+}
+
+/// A line of input without its terminator, numbered from 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Builds a [`ParseError`] for `token`, a slice of this line's text.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at(self.number, self.text, token, expected)
+    }
+}
+
+/// Iterates over the lines of `input`.
+///
+/// `\r\n` is treated like `\n`, and the newline ending the last line does not
+/// start an extra empty one.
+pub fn lines(input: &str, blank_lines: BlankLines) -> Lines<'_> {
+    let input = input
+        .strip_suffix('\n')
+        .map(|rest| rest.strip_suffix('\r').unwrap_or(rest))
+        .unwrap_or(input);
+
+    Lines {
+        inner: (!input.is_empty()).then(|| input.split('\n').enumerate()),
+        first_number: 1,
+        blank_lines,
+    }
+}
+
+/// A run of whole lines cut from a larger input, so parts of the input can
+/// be worked on separately.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Chunk<'a> {
+    /// The number of the chunk's first line within the whole input.
+    pub first_number: usize,
+    /// The chunk's lines, without the newline that ends the last one.
+    pub text: &'a str,
+}
+
+impl<'a> Chunk<'a> {
+    /// Iterates over the chunk's lines, numbered as in the whole input.
+    pub fn lines(&self, blank_lines: BlankLines) -> Lines<'a> {
+        Lines {
+            inner: Some(self.text.split('\n').enumerate()),
+            first_number: self.first_number,
+            blank_lines,
+        }
+    }
+}
+
+/// Splits `input` into at most `count` chunks of whole lines and about equal
+/// size. Reading the lines of every chunk in order gives the same lines, with
+/// the same numbers, as [`lines`] on the whole input.
+pub fn chunks(input: &str, count: usize) -> Vec<Chunk<'_>> {
+    let input = input
+        .strip_suffix('\n')
+        .map(|rest| rest.strip_suffix('\r').unwrap_or(rest))
+        .unwrap_or(input);
+    if input.is_empty() {
+        return Vec::new();
+    }
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut first_number = 1;
+    for index in 1..count.max(1) {
+        let target = (input.len() * index / count).max(start);
+        // Search bytes, since `target` may fall inside a character.
+        let newline = input.as_bytes()[target..]
+            .iter()
+            .position(|&byte| byte == b'\n');
+        let Some(end) = newline.map(|offset| target + offset) else {
+            break;
+        };
+
+        let text = &input[start..end];
+        chunks.push(Chunk { first_number, text });
+        first_number += text.matches('\n').count() + 1;
+        start = end + 1;
+    }
+    chunks.push(Chunk {
+        first_number,
+        text: &input[start..],
+    });
+
+    chunks
+}
+
+pub struct Lines<'a> {
+    inner: Option<std::iter::Enumerate<std::str::Split<'a, char>>>,
+    first_number: usize,
+    blank_lines: BlankLines,
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Result<Line<'a>, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (index, text) = self.inner.as_mut()?.next()?;
+            let line = Line {
+                number: self.first_number + index,
+                text: text.strip_suffix('\r').unwrap_or(text),
+            };
+
+            if !line.text.trim().is_empty() {
+                return Some(Ok(line));
+            }
+            if self.blank_lines == BlankLines::Error {
+                return Some(Err(line.error(line.text, "a non-blank line")));
+            }
+        }
+    }
+}
+
+mod test {
+    #[cfg(test)]
+    mod lines {
+        use super::super::{lines, BlankLines, Line};
+        use crate::ParseError;
+
+        fn texts(input: &str, blank_lines: BlankLines) -> Vec<(usize, &str)> {
+            lines(input, blank_lines)
+                .map(|line| line.map(|Line { number, text }| (number, text)))
+                .collect::<Result<_, _>>()
+                .unwrap()
+        }
+
+        #[test]
+        fn test_trailing_newline() {
+            assert_eq!(
+                texts("1abc2\ntreb7uchet\n", BlankLines::Error),
+                vec![(1, "1abc2"), (2, "treb7uchet")]
+            );
+        }
+
+        #[test]
+        fn test_no_trailing_newline() {
+            assert_eq!(
+                texts("1abc2\ntreb7uchet", BlankLines::Error),
+                vec![(1, "1abc2"), (2, "treb7uchet")]
+            );
+        }
+
+        #[test]
+        fn test_crlf() {
+            assert_eq!(
+                texts("1abc2\r\ntreb7uchet\r\n", BlankLines::Error),
+                vec![(1, "1abc2"), (2, "treb7uchet")]
+            );
+        }
+
+        #[test]
+        fn test_empty_input() {
+            assert_eq!(texts("", BlankLines::Error), vec![]);
+            assert_eq!(texts("\n", BlankLines::Error), vec![]);
+            assert_eq!(texts("\r\n", BlankLines::Error), vec![]);
+        }
+
+        #[test]
+        fn test_skip_blank_lines() {
+            assert_eq!(
+                texts("1abc2\n\n  \r\ntreb7uchet\n\n", BlankLines::Skip),
+                vec![(1, "1abc2"), (4, "treb7uchet")]
+            );
+        }
+
+        #[test]
+        fn test_error_on_blank_line() {
+            let result: Result<Vec<_>, _> =
+                lines("1abc2\r\n\r\ntreb7uchet", BlankLines::Error).collect();
+
+            assert_eq!(
+                result,
+                Err(ParseError {
+                    line: 2,
+                    column: 1,
+                    token: String::new(),
+                    expected: "a non-blank line".to_string(),
+                })
+            );
+        }
+
+        #[test]
+        fn test_error_on_extra_trailing_newline() {
+            let result: Result<Vec<_>, _> = lines("1abc2\n\n", BlankLines::Error).collect();
+
+            assert_eq!(result.unwrap_err().line, 2);
+        }
+    }
+
+    #[cfg(test)]
+    mod chunks {
+        use super::super::{chunks, lines, BlankLines, Chunk};
+
+        fn chunked(input: &str, count: usize) -> Vec<(usize, String)> {
+            chunks(input, count)
+                .iter()
+                .flat_map(|chunk| chunk.lines(BlankLines::Skip))
+                .map(|line| line.map(|line| (line.number, line.text.to_string())))
+                .collect::<Result<_, _>>()
+                .unwrap()
+        }
+
+        #[test]
+        fn test_same_lines_as_whole_input() {
+            let input = "1abc2\r\npqr3stu8vwx\n\na1b2c3d4e5f\ntreb7uchet\nx\n";
+            let whole: Vec<(usize, String)> = lines(input, BlankLines::Skip)
+                .map(|line| line.map(|line| (line.number, line.text.to_string())))
+                .collect::<Result<_, _>>()
+                .unwrap();
+
+            for count in 1..10 {
+                assert_eq!(chunked(input, count), whole, "{count} chunks");
+            }
+        }
+
+        #[test]
+        fn test_chunk_boundaries() {
+            assert_eq!(
+                chunks("ab\ncd\nef\ngh\n", 2),
+                vec![
+                    Chunk {
+                        first_number: 1,
+                        text: "ab\ncd",
+                    },
+                    Chunk {
+                        first_number: 3,
+                        text: "ef\ngh",
+                    },
+                ]
+            );
+            assert_eq!(chunks("", 4), vec![]);
+            assert_eq!(chunks("\n", 4), vec![]);
+            assert_eq!(chunks("one line", 4).len(), 1);
+            assert_eq!(chunks("ééééé\néé", 3).len(), 2);
+        }
+
+        #[test]
+        fn test_blank_line_is_kept() {
+            let errors: Vec<usize> = chunks("a\n\nb\n", 3)
+                .iter()
+                .flat_map(|chunk| chunk.lines(BlankLines::Error))
+                .filter_map(|line| line.err())
+                .map(|error| error.line)
+                .collect();
+
+            assert_eq!(errors, vec![2]);
+        }
+    }
+}
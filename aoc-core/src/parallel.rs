@@ -0,0 +1,101 @@
+//! Solving a day on several threads.
+//!
+//! Days whose answer is a sum over independent lines can cut their input
+//! into chunks of whole lines, parse and solve each chunk on its own thread,
+//! and add up the results.
+
+use std::thread;
+
+use crate::lines::{chunks, Chunk};
+use crate::{Natural, ParseError, SolveError};
+
+/// Splits `input` into `threads` chunks, parses each with `parse` and solves
+/// it with `part` on a thread of its own, and sums the answers. `threads` is
+/// capped at the machine's available parallelism, so a large count cannot
+/// spawn more threads than there are cores to run them.
+///
+/// Errors come out as if the whole input had been parsed and then solved on
+/// one thread: the first parse error in input order, or else the first error
+/// from `part`.
+pub fn sum_chunks<I>(
+    input: &str,
+    threads: usize,
+    parse: impl Fn(&Chunk<'_>) -> Result<I, ParseError> + Sync,
+    part: impl Fn(&I) -> Result<Natural, SolveError> + Sync,
+) -> Result<Natural, SolveError> {
+    let cores = thread::available_parallelism().map_or(1, usize::from);
+    let chunks = chunks(input, threads.min(cores));
+    let (parse, part) = (&parse, &part);
+
+    let results: Vec<Result<Result<Natural, SolveError>, ParseError>> = thread::scope(|scope| {
+        let workers: Vec<_> = chunks
+            .iter()
+            .map(|chunk| scope.spawn(move || parse(chunk).map(|input| part(&input))))
+            .collect();
+
+        workers
+            .into_iter()
+            .map(|worker| worker.join().expect("a solver thread panicked"))
+            .collect()
+    });
+
+    let answers = results.into_iter().collect::<Result<Vec<_>, _>>()?;
+    answers
+        .into_iter()
+        .try_fold(Natural::ZERO, |sum, answer| Ok(sum.checked_add(answer?)?))
+}
+
+mod test {
+    #[cfg(test)]
+    mod sum_chunks {
+        use super::super::sum_chunks;
+        use crate::lines::BlankLines;
+        use crate::{Natural, ParseError, SolveError};
+
+        fn numbers(input: &str, threads: usize) -> Result<Natural, SolveError> {
+            sum_chunks(
+                input,
+                threads,
+                |chunk| {
+                    chunk
+                        .lines(BlankLines::Error)
+                        .map(|line| {
+                            let line = line?;
+                            line.text
+                                .parse::<u64>()
+                                .map_err(|_| line.error(line.text, "a number"))
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                },
+                |numbers| match numbers.iter().position(|&number| number == 0) {
+                    Some(_) => {
+                        let line = "0";
+                        Err(ParseError::at(1, line, line, "no zero").into())
+                    }
+                    None => Ok(Natural::sum(numbers.iter().copied())?),
+                },
+            )
+        }
+
+        #[test]
+        fn test_sum() {
+            let input: String = (1..=100).map(|n| format!("{n}\n")).collect();
+
+            for threads in 1..8 {
+                assert_eq!(numbers(&input, threads), Ok(Natural::Small(5050)));
+            }
+        }
+
+        #[test]
+        fn test_parse_errors_come_first() {
+            let input = "0\n1\n2\n3\nx\n5\n";
+
+            for threads in 1..6 {
+                let Err(SolveError::Parse(error)) = numbers(input, threads) else {
+                    panic!("line 5 is not a number");
+                };
+                assert_eq!(error.line, 5);
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! A small seeded random number generator.
+//!
+//! Property tests and generated puzzle inputs need randomness that is the same
+//! on every run and every machine, so a failure can be replayed from its seed.
+//! This is SplitMix64: tiny, fast, and good enough for anything but
+//! cryptography.
+
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`. Panics if `bound` is 0.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "Rng::below needs a non-zero bound");
+
+        // Multiply-shift rather than modulo, so small bounds stay unbiased
+        // enough without a rejection loop.
+        ((u128::from(self.next_u64()) * bound as u128) >> 64) as usize
+    }
+
+    /// True with probability `probability`, clamped to `0.0..=1.0`.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+
+        unit < probability
+    }
+
+    /// A uniformly chosen element of `items`. Panics if `items` is empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
+
+mod test {
+    #[cfg(test)]
+    mod rng {
+        use super::super::Rng;
+
+        #[test]
+        fn test_same_seed_same_sequence() {
+            let mut first = Rng::new(7);
+            let mut second = Rng::new(7);
+
+            for _ in 0..100 {
+                assert_eq!(first.next_u64(), second.next_u64());
+            }
+            assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+        }
+
+        #[test]
+        fn test_below_stays_in_range() {
+            let mut rng = Rng::new(1);
+            let mut seen = [false; 6];
+
+            for _ in 0..1000 {
+                seen[rng.below(6)] = true;
+            }
+            assert!(seen.iter().all(|&seen| seen));
+        }
+
+        #[test]
+        fn test_chance_extremes() {
+            let mut rng = Rng::new(3);
+
+            assert!((0..100).all(|_| !rng.chance(0.0)));
+            assert!((0..100).all(|_| rng.chance(1.0)));
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::any::Any;
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::stream::ReadError;
+use crate::{Answer, ParseError, SolveError};
+
+/// A single day's puzzle.
+///
+/// The raw puzzle input is parsed once into [`Solution::Input`], and both
+/// parts are then computed from that shared, typed representation.
+pub trait Solution {
+    /// The parsed form of the puzzle input, shared by both parts.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError>;
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError>;
+
+    fn part(input: &Self::Input, part: Part) -> Result<Answer, SolveError> {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+
+    /// Solves `part` straight from the raw input, on up to `threads` threads
+    /// where the day knows how to split up its work. The default parses the
+    /// whole input and solves it on the calling thread.
+    fn solve(input: &str, part: Part, threads: usize) -> Result<Answer, SolveError> {
+        let _ = threads;
+
+        Self::part(&Self::parse(input)?, part)
+    }
+
+    /// Solves each of `parts` from whatever `reader` yields. The default reads
+    /// the whole input first; days that can work a line at a time override it
+    /// to run in constant memory.
+    fn solve_reader(reader: &mut dyn BufRead, parts: &[Part]) -> Result<Vec<Answer>, ReadError> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        let located = |error: SolveError| ReadError::located(error, &input);
+
+        let parsed = Self::parse(&input).map_err(|error| located(error.into()))?;
+        parts
+            .iter()
+            .map(|&part| Self::part(&parsed, part).map_err(located))
+            .collect()
+    }
+}
+
+/// One of the two halves of a puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!(
+                "there is no part {s:?}, puzzles only have parts 1 and 2"
+            )),
+        }
+    }
+}
+
+/// Object-safe view of a [`Solution`], so days with different input types can
+/// sit side by side in a registry.
+///
+/// Every `Solution` is a `Solver` through the blanket implementation below.
+pub trait Solver: Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Runs `part` on a value previously returned by [`Solver::parse`].
+    fn part(&self, input: &dyn Any, part: Part) -> Result<Answer, SolveError>;
+
+    /// See [`Solution::solve`].
+    fn solve(&self, input: &str, part: Part, threads: usize) -> Result<Answer, SolveError>;
+
+    /// See [`Solution::solve_reader`].
+    fn solve_reader(
+        &self,
+        reader: &mut dyn BufRead,
+        parts: &[Part],
+    ) -> Result<Vec<Answer>, ReadError>;
+}
+
+impl<S> Solver for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part(&self, input: &dyn Any, part: Part) -> Result<Answer, SolveError> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Solver::part called with input parsed by another day.");
+
+        S::part(input, part)
+    }
+
+    fn solve(&self, input: &str, part: Part, threads: usize) -> Result<Answer, SolveError> {
+        S::solve(input, part, threads)
+    }
+
+    fn solve_reader(
+        &self,
+        reader: &mut dyn BufRead,
+        parts: &[Part],
+    ) -> Result<Vec<Answer>, ReadError> {
+        S::solve_reader(reader, parts)
+    }
+}
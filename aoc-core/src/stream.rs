@@ -0,0 +1,294 @@
+//! Solving straight from a reader, one line at a time.
+//!
+//! Days whose answer is a sum over lines don't need the whole input at once.
+//! Reading through a [`BufRead`] keeps memory flat however large the input,
+//! so generated inputs can be piped in from another process or read through
+//! a decompressor.
+
+use std::fmt;
+use std::io::{self, BufRead};
+
+use crate::lines::{BlankLines, Line};
+use crate::{Natural, Overflow, ParseError, Part, SolveError};
+
+/// Why solving from a reader failed.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    /// A bad line; `text` is the line itself, since the rest of the input is
+    /// gone by the time the error is reported.
+    Parse {
+        error: ParseError,
+        text: String,
+    },
+    Overflow(Overflow),
+}
+
+impl ReadError {
+    /// Keeps the line of `input` that `error` points at, if any.
+    pub fn located(error: SolveError, input: &str) -> ReadError {
+        match error {
+            SolveError::Parse(error) => ReadError::Parse {
+                text: input
+                    .split('\n')
+                    .nth(error.line - 1)
+                    .unwrap_or("")
+                    .to_string(),
+                error,
+            },
+            SolveError::Overflow(error) => ReadError::Overflow(error),
+        }
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(error) => write!(f, "cannot read input: {error}"),
+            ReadError::Parse { error, .. } => write!(f, "{error}"),
+            ReadError::Overflow(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Io(error) => Some(error),
+            ReadError::Parse { error, .. } => Some(error),
+            ReadError::Overflow(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(error: io::Error) -> Self {
+        ReadError::Io(error)
+    }
+}
+
+/// Reads `reader` line by line and sums, for each of `parts`, what
+/// `line_answers` says every line contributes to it. It is given each line
+/// once, with all of `parts`, and returns one contribution per part in the
+/// same order, so a line is parsed once however many parts are asked for.
+///
+/// Lines are numbered and trimmed as by [`crate::lines::lines`]. The first
+/// bad line ends the search, whether parsing it or solving it failed.
+pub fn sum_lines(
+    mut reader: impl BufRead,
+    parts: &[Part],
+    blank_lines: BlankLines,
+    mut line_answers: impl FnMut(Line<'_>, &[Part]) -> Result<Vec<Natural>, SolveError>,
+) -> Result<Vec<Natural>, ReadError> {
+    let mut sums = vec![Natural::ZERO; parts.len()];
+    let mut buffer = String::new();
+    let mut number = 0;
+
+    loop {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            return Ok(sums);
+        }
+        number += 1;
+
+        let text = buffer.strip_suffix('\n').unwrap_or(&buffer);
+        let line = Line {
+            number,
+            text: text.strip_suffix('\r').unwrap_or(text),
+        };
+        let located = |error: SolveError| match error {
+            SolveError::Parse(error) => ReadError::Parse {
+                error,
+                text: line.text.to_string(),
+            },
+            SolveError::Overflow(error) => ReadError::Overflow(error),
+        };
+
+        if line.text.trim().is_empty() {
+            // A lone newline is an empty input, as it is for `lines`.
+            if number == 1 && line.text.is_empty() && reader.fill_buf()?.is_empty() {
+                return Ok(sums);
+            }
+            match blank_lines {
+                BlankLines::Skip => continue,
+                BlankLines::Error => {
+                    let error = line.error(line.text, "a non-blank line");
+                    return Err(located(error.into()));
+                }
+            }
+        }
+        let answers = line_answers(line, parts).map_err(located)?;
+        debug_assert_eq!(answers.len(), parts.len(), "one answer per part");
+        for (sum, answer) in sums.iter_mut().zip(answers) {
+            *sum = std::mem::replace(sum, Natural::ZERO)
+                .checked_add(answer)
+                .map_err(ReadError::Overflow)?;
+        }
+    }
+}
+
+mod test {
+    #[cfg(test)]
+    mod sum_lines {
+        use std::io::{self, BufReader, Read};
+
+        use super::super::{sum_lines, ReadError};
+        use crate::lines::{lines, BlankLines, Line};
+        use crate::{Natural, Part, SolveError};
+
+        fn numbers(input: &[u8], blank_lines: BlankLines) -> Result<Vec<Natural>, ReadError> {
+            sum_lines(input, &Part::ALL, blank_lines, number)
+        }
+
+        /// Part one sums the numbers, part two counts them.
+        fn number(line: Line<'_>, parts: &[Part]) -> Result<Vec<Natural>, SolveError> {
+            let number: u64 = line
+                .text
+                .parse()
+                .map_err(|_| line.error(line.text, "a number"))?;
+
+            Ok(parts
+                .iter()
+                .map(|part| {
+                    Natural::Small(match part {
+                        Part::One => number,
+                        Part::Two => 1,
+                    })
+                })
+                .collect())
+        }
+
+        /// `count` copies of `line`, made up as they are read.
+        struct Repeat {
+            line: &'static [u8],
+            count: usize,
+            offset: usize,
+        }
+
+        impl Read for Repeat {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                if self.count == 0 {
+                    return Ok(0);
+                }
+                let rest = &self.line[self.offset..];
+                let len = rest.len().min(buf.len());
+                buf[..len].copy_from_slice(&rest[..len]);
+                self.offset += len;
+                if self.offset == self.line.len() {
+                    (self.offset, self.count) = (0, self.count - 1);
+                }
+
+                Ok(len)
+            }
+        }
+
+        #[test]
+        fn test_sums() {
+            assert_eq!(
+                numbers(b"1\r\n2\n3", BlankLines::Error).unwrap(),
+                vec![Natural::Small(6), Natural::Small(3)]
+            );
+            assert_eq!(
+                numbers(b"", BlankLines::Error).unwrap(),
+                vec![Natural::ZERO, Natural::ZERO]
+            );
+        }
+
+        #[test]
+        fn test_blank_lines() {
+            assert_eq!(
+                numbers(b"1\n\n2\n", BlankLines::Skip).unwrap(),
+                vec![Natural::Small(3), Natural::Small(2)]
+            );
+
+            let Err(ReadError::Parse { error, text }) = numbers(b"1\n \n2\n", BlankLines::Error)
+            else {
+                panic!("a blank line should be an error");
+            };
+            assert_eq!((error.line, text.as_str()), (2, " "));
+        }
+
+        #[test]
+        fn test_agrees_with_lines() {
+            for input in ["", "\n", "\r\n", "a\n", "a\n\n", "\n\n", " \n"] {
+                let whole = lines(input, BlankLines::Error)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|lines| vec![Natural::from(lines.len())])
+                    .map_err(|error| error.line);
+                let streamed =
+                    sum_lines(input.as_bytes(), &[Part::One], BlankLines::Error, |_, _| {
+                        Ok(vec![Natural::Small(1)])
+                    })
+                    .map_err(|error| match error {
+                        ReadError::Parse { error, .. } => error.line,
+                        error => panic!("{error}"),
+                    });
+
+                assert_eq!(streamed, whole, "{input:?}");
+            }
+        }
+
+        #[test]
+        fn test_each_line_once() {
+            let mut calls = 0;
+            let sums = sum_lines(
+                &b"1\n2\n"[..],
+                &Part::ALL,
+                BlankLines::Error,
+                |line, parts| {
+                    calls += 1;
+                    number(line, parts)
+                },
+            );
+
+            assert_eq!(sums.unwrap(), vec![Natural::Small(3), Natural::Small(2)]);
+            assert_eq!(calls, 2);
+        }
+
+        #[test]
+        fn test_error_keeps_line() {
+            let Err(ReadError::Parse { error, text }) = numbers(b"1\n2\nx3\n", BlankLines::Error)
+            else {
+                panic!("x3 is not a number");
+            };
+
+            assert_eq!((error.line, error.column), (3, 1));
+            assert_eq!(error.line_snippet(&text), "  |\n3 | x3\n  | ^^");
+        }
+
+        #[test]
+        fn test_large_input_from_reader() {
+            let reader = Repeat {
+                line: b"12\n",
+                count: 1_000_000,
+                offset: 0,
+            };
+            let sums = sum_lines(
+                BufReader::new(reader),
+                &[Part::One],
+                BlankLines::Error,
+                number,
+            );
+
+            assert_eq!(sums.unwrap(), vec![Natural::Small(12_000_000)]);
+        }
+
+        #[test]
+        fn test_invalid_utf8() {
+            let result = numbers(b"1\n\xff\n", BlankLines::Error);
+
+            assert!(matches!(result, Err(ReadError::Io(_))));
+        }
+
+        #[cfg(not(feature = "bigint"))]
+        #[test]
+        fn test_overflow() {
+            let input = format!("{}\n1\n", u64::MAX);
+
+            assert!(matches!(
+                numbers(input.as_bytes(), BlankLines::Error),
+                Err(ReadError::Overflow(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Minimal command-line argument handling for the `aoc` binary.
+
+use aoc::Error;
+
+/// The arguments after the subcommand name.
+///
+/// Options are pulled out by name first; whatever is left is positional.
+/// Everything after `--` is positional, even if it looks like an option.
+pub struct Args {
+    rest: Vec<String>,
+    operands: Vec<String>,
+}
+
+impl Args {
+    pub fn new(mut rest: Vec<String>) -> Self {
+        let operands = match rest.iter().position(|arg| arg == "--") {
+            Some(end) => rest.split_off(end).split_off(1),
+            None => Vec::new(),
+        };
+
+        Args { rest, operands }
+    }
+
+    /// Removes `--name VALUE` or `--name=VALUE` and returns the value.
+    pub fn option(&mut self, name: &str) -> Result<Option<String>, Error> {
+        let prefix = format!("{name}=");
+        let Some(index) = self
+            .rest
+            .iter()
+            .position(|arg| arg == name || arg.starts_with(&prefix))
+        else {
+            return Ok(None);
+        };
+
+        let arg = self.rest.remove(index);
+        if let Some(value) = arg.strip_prefix(&prefix) {
+            return Ok(Some(value.to_string()));
+        }
+        if index < self.rest.len() {
+            return Ok(Some(self.rest.remove(index)));
+        }
+
+        Err(Error::Usage(format!("{name} expects a value")))
+    }
+
+    /// Removes `--name` and reports whether it was present.
+    pub fn flag(&mut self, name: &str) -> bool {
+        let before = self.rest.len();
+        self.rest.retain(|arg| arg != name);
+        self.rest.len() != before
+    }
+
+    /// Returns the remaining positional arguments, rejecting unknown options.
+    /// A negative number like `-5` is positional, not an option.
+    pub fn positional(mut self) -> Result<Vec<String>, Error> {
+        if let Some(unknown) = self.rest.iter().find(|arg| is_option(arg)) {
+            return Err(Error::Usage(format!("unknown option {unknown}")));
+        }
+
+        self.rest.append(&mut self.operands);
+        Ok(self.rest)
+    }
+}
+
+fn is_option(arg: &str) -> bool {
+    match arg.strip_prefix('-') {
+        Some(rest) => !rest.is_empty() && rest.parse::<i128>().is_err(),
+        None => false,
+    }
+}
+
+pub fn parse_number<T: std::str::FromStr>(value: &str, what: &str) -> Result<T, Error> {
+    value
+        .parse()
+        .map_err(|_| Error::Usage(format!("{what} must be a number, got {value:?}")))
+}
+
+mod test {
+    #[cfg(test)]
+    mod positional {
+        use super::super::Args;
+
+        fn args(args: &[&str]) -> Args {
+            Args::new(args.iter().map(|arg| arg.to_string()).collect())
+        }
+
+        #[test]
+        fn test_negative_numbers() {
+            let positional = args(&["2023", "1", "1", "-5"]).positional().unwrap();
+
+            assert_eq!(positional, ["2023", "1", "1", "-5"]);
+        }
+
+        #[test]
+        fn test_end_of_options() {
+            let mut parsed = args(&["2023", "--input", "a", "--", "--input", "-x"]);
+
+            assert_eq!(parsed.option("--input").unwrap().as_deref(), Some("a"));
+            assert_eq!(parsed.positional().unwrap(), ["2023", "--input", "-x"]);
+        }
+
+        #[test]
+        fn test_unknown_options() {
+            assert!(args(&["2023", "-x"]).positional().is_err());
+            assert!(args(&["2023", "--stream"]).positional().is_err());
+            assert_eq!(args(&["-"]).positional().unwrap(), ["-"]);
+        }
+    }
+}
@@ -0,0 +1,336 @@
+//! Timing each day's parse step and parts separately.
+//!
+//! Every run is appended to a history file in the data directory so the next
+//! run can be compared with it and slowdowns stand out.
+
+use std::fmt;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::{fs, io};
+
+use crate::{Error, Store};
+use aoc_core::{Day, Part, SolveError};
+
+pub const HISTORY_FILE: &str = "bench-history.tsv";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+impl Stage {
+    const ALL: [Stage; 3] = [Stage::Parse, Stage::Part(Part::One), Stage::Part(Part::Two)];
+
+    fn parse(name: &str) -> Option<Stage> {
+        Stage::ALL
+            .into_iter()
+            .find(|stage| stage.to_string() == name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarizes a non-empty set of samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let count = sorted.len() as f64;
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / count;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        Stats {
+            mean: Duration::from_secs_f64(mean),
+            median,
+            min: sorted[0],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub stage: Stage,
+    pub iterations: usize,
+    pub stats: Stats,
+}
+
+/// Times `day` on `input`: the parse step, then each part on a single parsed
+/// copy, `iterations` times each.
+pub fn measure(day: &Day, input: &str, iterations: usize) -> Result<Vec<Measurement>, Error> {
+    let iterations = iterations.max(1);
+    let located = |error: SolveError| Error::solving(error, input);
+    let mut measurements = Vec::new();
+    let mut record = |stage, samples: Vec<Duration>| {
+        measurements.push(Measurement {
+            year: day.year,
+            day: day.day,
+            stage,
+            iterations,
+            stats: Stats::from_samples(&samples),
+        })
+    };
+
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = day.solver.parse(black_box(input));
+        let parsed = parsed.map_err(|error| located(error.into()))?;
+        samples.push(start.elapsed());
+        drop(black_box(parsed));
+    }
+    record(Stage::Parse, samples);
+
+    let parsed = day.solver.parse(input);
+    let parsed = parsed.map_err(|error| located(error.into()))?;
+    for part in Part::ALL {
+        let mut samples = Vec::with_capacity(iterations);
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let answer = day.solver.part(black_box(parsed.as_ref()), part);
+            samples.push(start.elapsed());
+            black_box(answer.map_err(located)?);
+        }
+        record(Stage::Part(part), samples);
+    }
+
+    Ok(measurements)
+}
+
+/// Past benchmark results, oldest first.
+pub struct History {
+    entries: Vec<(u64, Measurement)>,
+}
+
+impl History {
+    const HEADER: &'static str =
+        "# timestamp\tyear\tday\tstage\titerations\tmean_ns\tmedian_ns\tmin_ns\tstddev_ns";
+
+    pub fn path(store: &Store) -> std::path::PathBuf {
+        store.root().join(HISTORY_FILE)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(error) => {
+                return Err(Error::File {
+                    path: path.to_path_buf(),
+                    error,
+                })
+            }
+        };
+
+        let entries = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                parse_entry(line).ok_or_else(|| Error::Corrupt {
+                    path: path.to_path_buf(),
+                    line: line.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(History { entries })
+    }
+
+    /// The most recent earlier result for the same day and stage.
+    pub fn previous(&self, measurement: &Measurement) -> Option<&Measurement> {
+        self.entries
+            .iter()
+            .rev()
+            .map(|(_, entry)| entry)
+            .find(|entry| {
+                (entry.year, entry.day, entry.stage)
+                    == (measurement.year, measurement.day, measurement.stage)
+            })
+    }
+
+    /// Appends `measurements` to the history file at `path`.
+    pub fn append(path: &Path, measurements: &[Measurement]) -> Result<(), Error> {
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => format!("{}\n", Self::HEADER),
+            Err(error) => return Err(error.into()),
+        };
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        for m in measurements {
+            contents.push_str(&format!(
+                "{timestamp}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+                m.year,
+                m.day,
+                m.stage,
+                m.iterations,
+                m.stats.mean.as_nanos(),
+                m.stats.median.as_nanos(),
+                m.stats.min.as_nanos(),
+                m.stats.stddev.as_nanos()
+            ));
+        }
+        fs::write(path, contents)?;
+
+        Ok(())
+    }
+}
+
+fn parse_entry(line: &str) -> Option<(u64, Measurement)> {
+    let fields: Vec<&str> = line.split('\t').collect();
+    let [timestamp, year, day, stage, iterations, mean, median, min, stddev] = fields[..] else {
+        return None;
+    };
+    let nanos = |field: &str| field.parse().ok().map(Duration::from_nanos);
+
+    Some((
+        timestamp.parse().ok()?,
+        Measurement {
+            year: year.parse().ok()?,
+            day: day.parse().ok()?,
+            stage: Stage::parse(stage)?,
+            iterations: iterations.parse().ok()?,
+            stats: Stats {
+                mean: nanos(mean)?,
+                median: nanos(median)?,
+                min: nanos(min)?,
+                stddev: nanos(stddev)?,
+            },
+        },
+    ))
+}
+
+/// How much slower the median of `current` is than that of `previous`, as a
+/// fraction (0.25 means 25% slower, negative means faster).
+pub fn slowdown(previous: &Measurement, current: &Measurement) -> f64 {
+    let before = previous.stats.median.as_secs_f64();
+    if before == 0.0 {
+        return 0.0;
+    }
+
+    current.stats.median.as_secs_f64() / before - 1.0
+}
+
+mod test {
+    #[cfg(test)]
+    mod stats {
+        use super::super::Stats;
+        use std::time::Duration;
+
+        #[test]
+        fn test_odd_samples() {
+            let samples = [5, 1, 3].map(Duration::from_micros);
+            let stats = Stats::from_samples(&samples);
+
+            assert_eq!(stats.mean, Duration::from_micros(3));
+            assert_eq!(stats.median, Duration::from_micros(3));
+            assert_eq!(stats.min, Duration::from_micros(1));
+            assert_eq!(stats.stddev.as_nanos(), 1633);
+        }
+
+        #[test]
+        fn test_even_samples() {
+            let samples = [4, 2, 8, 6].map(Duration::from_micros);
+            let stats = Stats::from_samples(&samples);
+
+            assert_eq!(stats.median, Duration::from_micros(5));
+            assert_eq!(stats.min, Duration::from_micros(2));
+        }
+    }
+
+    #[cfg(test)]
+    mod history {
+        use super::super::{measure, slowdown, History, Stage};
+        use crate::registry;
+        use crate::store::temp_dir;
+        use crate::Error;
+        use aoc_core::Part;
+        use std::time::Duration;
+
+        #[test]
+        fn test_round_trip_and_compare() {
+            let root = temp_dir("bench");
+            let path = root.join("bench-history.tsv");
+            let day = registry::find(2023, 2).unwrap();
+            let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n";
+
+            let first = measure(day, input, 3).unwrap();
+            assert_eq!(
+                first.iter().map(|m| m.stage).collect::<Vec<_>>(),
+                vec![Stage::Parse, Stage::Part(Part::One), Stage::Part(Part::Two)]
+            );
+            History::append(&path, &first).unwrap();
+
+            let mut second = first.clone();
+            second[1].stats.median = first[1].stats.median * 2 + Duration::from_nanos(2);
+
+            let history = History::load(&path).unwrap();
+            let previous = history.previous(&second[1]).unwrap();
+            assert_eq!(
+                previous.stats.median.as_nanos(),
+                first[1].stats.median.as_nanos()
+            );
+            assert!(slowdown(previous, &second[1]) >= 1.0);
+        }
+
+        #[test]
+        fn test_bad_history() {
+            let root = temp_dir("bench");
+            std::fs::create_dir_all(root.path()).unwrap();
+            let path = root.join("bench-history.tsv");
+            std::fs::write(&path, "1700000000\t2023\t1\n").unwrap();
+
+            assert!(matches!(History::load(&path), Err(Error::Corrupt { .. })));
+            assert!(matches!(
+                History::load(root.path()),
+                Err(Error::File { .. })
+            ));
+        }
+
+        #[test]
+        fn test_parse_error() {
+            let day = registry::find(2023, 1).unwrap();
+
+            assert!(measure(day, "abc\n", 1).is_err());
+        }
+    }
+}
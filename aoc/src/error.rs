@@ -0,0 +1,146 @@
+use std::path::PathBuf;
+use std::time::Duration;
+use std::{fmt, io};
+
+use aoc_core::stream::ReadError;
+use aoc_core::{Overflow, ParseError, SolveError};
+
+#[derive(Debug)]
+pub enum Error {
+    /// The date exists in the calendar but has no registered solution.
+    NotImplemented {
+        year: u16,
+        day: u8,
+    },
+    /// The date is not an Advent of Code puzzle at all.
+    NoSuchDay {
+        year: u16,
+        day: u8,
+    },
+    /// No input has been stored for this day and profile.
+    MissingInput {
+        year: u16,
+        day: u8,
+        profile: String,
+    },
+    /// The puzzle input was rejected; `snippet` shows where.
+    Parse {
+        error: ParseError,
+        snippet: String,
+    },
+    /// The answer is too large to compute without the `bigint` feature.
+    Overflow(Overflow),
+    /// Talking to adventofcode.com needs a session cookie, and none is set.
+    MissingSession,
+    /// A submission was refused locally because the last one was too recent.
+    Cooldown {
+        remaining: Duration,
+    },
+    /// The answer is known to be wrong without asking adventofcode.com.
+    KnownWrong {
+        answer: String,
+        reason: String,
+    },
+    /// `verify` found answers that changed or could not be computed.
+    Verification {
+        failures: usize,
+    },
+    /// A file could not be read.
+    File {
+        path: PathBuf,
+        error: io::Error,
+    },
+    /// A file the tool keeps has a line it cannot read.
+    Corrupt {
+        path: PathBuf,
+        line: String,
+    },
+    /// adventofcode.com could not be reached or answered unexpectedly.
+    Remote(String),
+    Usage(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotImplemented { year, day } => {
+                write!(f, "{year} day {day} is not implemented yet")
+            }
+            Error::NoSuchDay { year, day } => {
+                write!(f, "{year} day {day} is not an Advent of Code puzzle")
+            }
+            Error::MissingInput { year, day, profile } => write!(
+                f,
+                "no input stored for {year} day {day} (profile {profile:?}); add one with `aoc input add {year} {day} FILE`"
+            ),
+            Error::Parse { error, snippet } => write!(f, "{error}\n{snippet}"),
+            Error::Overflow(error) => write!(f, "{error}"),
+            Error::MissingSession => write!(
+                f,
+                "no session cookie; set $AOC_SESSION or add `session = ...` to the config file"
+            ),
+            Error::Cooldown { remaining } => write!(
+                f,
+                "the last answer was submitted too recently, wait another {}s",
+                remaining.as_secs()
+            ),
+            Error::KnownWrong { answer, reason } => {
+                write!(f, "not submitting {answer}: {reason}")
+            }
+            Error::Verification { failures } => write!(f, "{failures} check(s) failed"),
+            Error::File { path, error } => write!(f, "cannot read {}: {error}", path.display()),
+            Error::Corrupt { path, line } => {
+                write!(f, "{}: cannot read line {line:?}", path.display())
+            }
+            Error::Remote(message) => write!(f, "{message}"),
+            Error::Usage(message) => write!(f, "{message}"),
+            Error::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Parse { error, .. } => Some(error),
+            Error::Overflow(error) => Some(error),
+            Error::File { error, .. } => Some(error),
+            Error::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Error {
+    /// An error from solving `input`, showing the offending line if there is
+    /// one.
+    pub(crate) fn solving(error: impl Into<SolveError>, input: &str) -> Error {
+        match error.into() {
+            SolveError::Parse(error) => Error::Parse {
+                snippet: error.snippet(input),
+                error,
+            },
+            SolveError::Overflow(error) => Error::Overflow(error),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<ReadError> for Error {
+    fn from(error: ReadError) -> Self {
+        match error {
+            ReadError::Io(error) => Error::Io(error),
+            ReadError::Parse { error, text } => Error::Parse {
+                snippet: error.line_snippet(&text),
+                error,
+            },
+            ReadError::Overflow(error) => Error::Overflow(error),
+        }
+    }
+}
@@ -0,0 +1,404 @@
+//! Answers that adventofcode.com accepted, and guesses it rejected.
+//!
+//! Accepted answers are kept in a tab-separated file meant to be checked in,
+//! so `aoc verify` can catch a refactor that changes a result. Each answer is
+//! keyed by the checksum of the input it was computed from, so the whole team
+//! can share one file. Rejected guesses stay local to each profile and stop
+//! the same wrong answer, or one outside the known bounds, from being sent
+//! twice.
+
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use crate::remote::Hint;
+use crate::{registry, store, Error, Store};
+use aoc_core::Part;
+
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.tsv";
+
+/// Which puzzle, and which input to it, an answer belongs to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Key {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// [`store::checksum`] of the input.
+    pub input: String,
+}
+
+impl Key {
+    pub fn new(year: u16, day: u8, part: Part, input: &str) -> Self {
+        Key {
+            year,
+            day,
+            part,
+            input: store::checksum(input),
+        }
+    }
+
+    fn fields(&self) -> String {
+        format!("{}\t{}\t{}\t{}", self.year, self.day, self.part, self.input)
+    }
+
+    /// Parses the key columns of a line, returning the remaining columns.
+    fn parse(line: &str) -> Option<(Key, Vec<&str>)> {
+        let mut fields = line.split('\t');
+        let key = Key {
+            year: fields.next()?.parse().ok()?,
+            day: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            input: fields.next()?.to_string(),
+        };
+
+        Some((key, fields.collect()))
+    }
+}
+
+/// Reads the data lines of a ledger file, which may not exist yet.
+fn data_lines(path: &Path) -> Result<Vec<String>, Error> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(contents
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
+            .collect()),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn corrupt(path: &Path, line: &str) -> Error {
+    Error::Corrupt {
+        path: path.to_path_buf(),
+        line: line.to_string(),
+    }
+}
+
+fn write_lines(path: &Path, header: &str, lines: &[String]) -> Result<(), Error> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut contents = format!("{header}\n");
+    for line in lines {
+        contents.push_str(line);
+        contents.push('\n');
+    }
+    fs::write(path, contents)?;
+
+    Ok(())
+}
+
+/// The accepted answers file.
+pub struct Ledger {
+    path: PathBuf,
+    entries: Vec<(Key, String)>,
+}
+
+impl Ledger {
+    const HEADER: &'static str = "# year\tday\tpart\tinput\tanswer";
+
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+        let entries = data_lines(&path)?
+            .iter()
+            .map(|line| match Key::parse(line) {
+                Some((key, rest)) if rest.len() == 1 => Ok((key, rest[0].to_string())),
+                _ => Err(corrupt(&path, line)),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Ledger { path, entries })
+    }
+
+    pub fn accepted(&self, key: &Key) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| entry == key)
+            .map(|(_, answer)| answer.as_str())
+    }
+
+    /// Stores `answer` for `key`, replacing an earlier one, and saves the file.
+    pub fn record(&mut self, key: Key, answer: &str) -> Result<(), Error> {
+        self.entries.retain(|(entry, _)| *entry != key);
+        self.entries.push((key, answer.to_string()));
+        self.entries.sort_by(|(a, _), (b, _)| {
+            (a.year, a.day, a.part, &a.input).cmp(&(b.year, b.day, b.part, &b.input))
+        });
+
+        let lines: Vec<String> = self
+            .entries
+            .iter()
+            .map(|(key, answer)| format!("{}\t{answer}", key.fields()))
+            .collect();
+        write_lines(&self.path, Self::HEADER, &lines)
+    }
+}
+
+/// Answers adventofcode.com rejected, for one profile.
+pub struct Guesses {
+    path: PathBuf,
+    entries: Vec<(Key, String, Option<Hint>)>,
+}
+
+impl Guesses {
+    const HEADER: &'static str = "# year\tday\tpart\tinput\tanswer\thint";
+
+    /// Loads the rejected guesses of `store`'s profile.
+    pub fn load(store: &Store) -> Result<Self, Error> {
+        let path = store.state_path("guesses.tsv");
+        let entries = data_lines(&path)?
+            .iter()
+            .map(|line| {
+                let (key, rest) = Key::parse(line).ok_or_else(|| corrupt(&path, line))?;
+                let hint = match rest.get(1).copied() {
+                    Some("high") => Some(Hint::TooHigh),
+                    Some("low") => Some(Hint::TooLow),
+                    Some("-") => None,
+                    _ => return Err(corrupt(&path, line)),
+                };
+                Ok((key, rest[0].to_string(), hint))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Guesses { path, entries })
+    }
+
+    /// Fails if `answer` is already known to be wrong for `key`, either
+    /// because it was rejected before or because it lies outside the bounds
+    /// given by earlier too-high and too-low hints.
+    pub fn vet(&self, key: &Key, answer: &str) -> Result<(), Error> {
+        let known_wrong = |reason: String| Error::KnownWrong {
+            answer: answer.to_string(),
+            reason,
+        };
+        let guesses = self.entries.iter().filter(|(entry, _, _)| entry == key);
+
+        for (_, guess, hint) in guesses {
+            if guess == answer {
+                return Err(known_wrong("it was already rejected".to_string()));
+            }
+
+            let (Ok(guess_value), Ok(value)) = (guess.parse::<i128>(), answer.parse::<i128>())
+            else {
+                continue;
+            };
+            match hint {
+                Some(Hint::TooHigh) if value >= guess_value => {
+                    return Err(known_wrong(format!("{guess} was already too high")));
+                }
+                Some(Hint::TooLow) if value <= guess_value => {
+                    return Err(known_wrong(format!("{guess} was already too low")));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, key: Key, answer: &str, hint: Option<Hint>) -> Result<(), Error> {
+        self.entries.push((key, answer.to_string(), hint));
+
+        let lines: Vec<String> = self
+            .entries
+            .iter()
+            .map(|(key, answer, hint)| {
+                let hint = match hint {
+                    Some(Hint::TooHigh) => "high",
+                    Some(Hint::TooLow) => "low",
+                    None => "-",
+                };
+                format!("{}\t{answer}\t{hint}", key.fields())
+            })
+            .collect();
+        write_lines(&self.path, Self::HEADER, &lines)
+    }
+}
+
+/// The outcome of re-solving one part of one day.
+#[derive(Debug)]
+pub enum Status {
+    Match,
+    /// The solution no longer produces the accepted answer.
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    /// No answer has been accepted for this input yet.
+    Unrecorded {
+        actual: String,
+    },
+    Failed(Error),
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub status: Status,
+}
+
+/// Re-solves every registered day that has a stored input and compares the
+/// results with the ledger.
+pub fn verify(store: &Store, ledger: &Ledger) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    for day in registry::days() {
+        let input = match store.read(day.year, day.day) {
+            Ok(input) => input,
+            Err(Error::MissingInput { .. }) => continue,
+            Err(error) => {
+                checks.push(Check {
+                    year: day.year,
+                    day: day.day,
+                    part: Part::One,
+                    status: Status::Failed(error),
+                });
+                continue;
+            }
+        };
+
+        for part in Part::ALL {
+            let key = Key::new(day.year, day.day, part, &input);
+            let status = match crate::run(day.year, day.day, &[part], &input) {
+                Err(error) => Status::Failed(error),
+                Ok(answers) => {
+                    let actual = answers[0].1.to_string();
+                    match ledger.accepted(&key) {
+                        Some(expected) if expected == actual => Status::Match,
+                        Some(expected) => Status::Mismatch {
+                            expected: expected.to_string(),
+                            actual,
+                        },
+                        None => Status::Unrecorded { actual },
+                    }
+                }
+            };
+
+            checks.push(Check {
+                year: day.year,
+                day: day.day,
+                part,
+                status,
+            });
+        }
+    }
+
+    checks
+}
+
+mod test {
+    #[cfg(test)]
+    mod ledger {
+        use super::super::{Key, Ledger};
+        use crate::store::temp_dir;
+        use crate::Error;
+        use aoc_core::Part;
+
+        #[test]
+        fn test_record_and_reload() {
+            let root = temp_dir("ledger");
+            let path = root.join("answers.tsv");
+            let key = Key::new(2023, 1, Part::One, "1abc2\n");
+
+            let mut ledger = Ledger::load(&path).unwrap();
+            assert_eq!(ledger.accepted(&key), None);
+            ledger.record(key.clone(), "12").unwrap();
+            ledger.record(key.clone(), "13").unwrap();
+
+            let reloaded = Ledger::load(&path).unwrap();
+            assert_eq!(reloaded.accepted(&key), Some("13"));
+            assert_eq!(
+                reloaded.accepted(&Key::new(2023, 1, Part::One, "2abc2\n")),
+                None
+            );
+        }
+
+        #[test]
+        fn test_corrupt_file() {
+            let root = temp_dir("ledger");
+            let path = root.join("answers.tsv");
+            std::fs::create_dir_all(root.path()).unwrap();
+            std::fs::write(&path, "2023\t1\tthree\tabc\t12\n").unwrap();
+
+            assert!(matches!(
+                Ledger::load(&path),
+                Err(Error::Corrupt { line, .. }) if line == "2023\t1\tthree\tabc\t12"
+            ));
+        }
+    }
+
+    #[cfg(test)]
+    mod guesses {
+        use super::super::{Guesses, Key};
+        use crate::remote::Hint;
+        use crate::store::{temp_dir, Store};
+        use crate::Error;
+        use aoc_core::Part;
+
+        #[test]
+        fn test_bounds() {
+            let root = temp_dir("guesses");
+            let store = Store::new(root.path(), "default").unwrap();
+            let key = Key::new(2023, 1, Part::Two, "two1nine\n");
+
+            let mut guesses = Guesses::load(&store).unwrap();
+            guesses
+                .record(key.clone(), "500", Some(Hint::TooHigh))
+                .unwrap();
+            guesses
+                .record(key.clone(), "100", Some(Hint::TooLow))
+                .unwrap();
+            guesses.record(key.clone(), "300", None).unwrap();
+
+            let guesses = Guesses::load(&store).unwrap();
+            let vet = |answer| guesses.vet(&key, answer);
+            assert!(matches!(vet("500"), Err(Error::KnownWrong { .. })));
+            assert!(matches!(vet("501"), Err(Error::KnownWrong { .. })));
+            assert!(matches!(vet("100"), Err(Error::KnownWrong { .. })));
+            assert!(matches!(vet("300"), Err(Error::KnownWrong { .. })));
+            assert!(vet("299").is_ok());
+            assert!(vet("not a number").is_ok());
+
+            let other_input = Key::new(2023, 1, Part::Two, "one\n");
+            assert!(guesses.vet(&other_input, "500").is_ok());
+        }
+    }
+
+    #[cfg(test)]
+    mod verify {
+        use super::super::{verify, Key, Ledger, Status};
+        use crate::store::{temp_dir, Store};
+        use aoc_core::Part;
+
+        #[test]
+        fn test_detects_regression() {
+            let root = temp_dir("verify");
+            let store = Store::new(root.path(), "default").unwrap();
+            let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+            store.add(2023, 1, input).unwrap();
+
+            let mut ledger = Ledger::load(root.join("answers.tsv")).unwrap();
+            ledger
+                .record(Key::new(2023, 1, Part::One, input), "142")
+                .unwrap();
+            ledger
+                .record(Key::new(2023, 1, Part::Two, input), "141")
+                .unwrap();
+
+            let checks = verify(&store, &ledger);
+
+            assert_eq!(checks.len(), 2);
+            assert!(matches!(checks[0].status, Status::Match));
+            assert!(matches!(
+                &checks[1].status,
+                Status::Mismatch { expected, actual } if expected == "141" && actual == "142"
+            ));
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Runner library behind the `aoc` binary: finds registered days and runs them.
+
+pub mod bench;
+mod error;
+pub mod ledger;
+pub mod registry;
+pub mod remote;
+pub mod store;
+
+pub use error::Error;
+pub use store::Store;
+
+use std::io::BufRead;
+
+use aoc_core::{Answer, Part};
+
+/// Parses `input` once for `day` and returns the answer of every requested part.
+pub fn run(year: u16, day: u8, parts: &[Part], input: &str) -> Result<Vec<(Part, Answer)>, Error> {
+    let day = registry::find(year, day)?;
+    let parsed = day
+        .solver
+        .parse(input)
+        .map_err(|error| Error::solving(error, input))?;
+
+    parts
+        .iter()
+        .map(|&part| {
+            let answer = day
+                .solver
+                .part(parsed.as_ref(), part)
+                .map_err(|error| Error::solving(error, input))?;
+            Ok((part, answer))
+        })
+        .collect()
+}
+
+/// Like [`run`], but each part is solved straight from `input` on up to
+/// `threads` threads, for days that can split up their work.
+#[cfg(feature = "parallel")]
+pub fn run_parallel(
+    year: u16,
+    day: u8,
+    parts: &[Part],
+    input: &str,
+    threads: usize,
+) -> Result<Vec<(Part, Answer)>, Error> {
+    let day = registry::find(year, day)?;
+
+    parts
+        .iter()
+        .map(|&part| {
+            let answer = day
+                .solver
+                .solve(input, part, threads)
+                .map_err(|error| Error::solving(error, input))?;
+            Ok((part, answer))
+        })
+        .collect()
+}
+
+/// Like [`run`], but reads the input from `reader` as it goes. Days that work
+/// a line at a time solve every part in one pass and constant memory.
+pub fn run_reader(
+    year: u16,
+    day: u8,
+    parts: &[Part],
+    reader: &mut dyn BufRead,
+) -> Result<Vec<(Part, Answer)>, Error> {
+    let day = registry::find(year, day)?;
+    let answers = day.solver.solve_reader(reader, parts)?;
+
+    Ok(parts.iter().copied().zip(answers).collect())
+}
+
+/// Like [`run`], using the input kept in `store` for that day.
+pub fn run_stored(
+    store: &Store,
+    year: u16,
+    day: u8,
+    parts: &[Part],
+) -> Result<Vec<(Part, Answer)>, Error> {
+    registry::find(year, day)?;
+    let input = store.read(year, day)?;
+
+    run(year, day, parts, &input)
+}
+
+mod test {
+    #[cfg(test)]
+    mod run_stored {
+        use super::super::{run_stored, store::temp_dir, Store};
+        use aoc_core::{Answer, Part};
+
+        #[test]
+        fn test_stored_input() {
+            let root = temp_dir("run");
+            let store = Store::new(root.path(), "default").unwrap();
+            store
+                .add(2023, 1, "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n")
+                .unwrap();
+
+            let answers = run_stored(&store, 2023, 1, &[Part::One]).unwrap();
+
+            assert_eq!(answers, vec![(Part::One, Answer::Int(142))]);
+        }
+    }
+
+    #[cfg(test)]
+    mod run_reader {
+        use super::super::{run, run_reader, Error};
+        use aoc_core::Part;
+
+        #[test]
+        fn test_same_answers() {
+            let input = "Game 1: 3 blue, 4 red; 1 red, 2 green\nGame 2: 1 blue\nGame 3: 20 red\n";
+            let mut reader = input.as_bytes();
+
+            assert_eq!(
+                run_reader(2023, 2, &Part::ALL, &mut reader).unwrap(),
+                run(2023, 2, &Part::ALL, input).unwrap()
+            );
+        }
+
+        #[test]
+        fn test_parse_error_snippet() {
+            let mut reader = "1abc2\npqrstuvwx\n".as_bytes();
+            let Err(Error::Parse { error, snippet }) = run_reader(2023, 1, &Part::ALL, &mut reader)
+            else {
+                panic!("line 2 has no digit");
+            };
+
+            assert_eq!(error.line, 2);
+            assert_eq!(snippet, "  |\n2 | pqrstuvwx\n  | ^^^^^^^^^");
+        }
+    }
+
+    #[cfg(all(test, feature = "parallel"))]
+    mod run_parallel {
+        use super::super::{run, run_parallel};
+        use aoc_core::Part;
+
+        #[test]
+        fn test_same_answers() {
+            let input = "Game 1: 3 blue, 4 red; 1 red, 2 green\nGame 2: 1 blue\nGame 3: 20 red\n";
+
+            for threads in 1..5 {
+                assert_eq!(
+                    run_parallel(2023, 2, &Part::ALL, input, threads).unwrap(),
+                    run(2023, 2, &Part::ALL, input).unwrap()
+                );
+            }
+        }
+    }
+}
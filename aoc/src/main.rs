@@ -0,0 +1,610 @@
+mod args;
+
+use std::fs;
+use std::io::{self, BufRead, BufReader, IsTerminal, Read};
+use std::process::ExitCode;
+
+use aoc::bench::{self, History};
+use aoc::ledger::{self, Guesses, Key, Ledger, Status};
+use aoc::remote::{Curl, Hint, Remote, Verdict};
+use aoc::{registry, store, Error, Store};
+use aoc2023::cube_conundrum::{self, Absent, Bag, Duplicates, Palette};
+use aoc2023::trebuchet::{self, explain::Show};
+use aoc_core::{Natural, Part};
+use args::{parse_number, Args};
+
+const USAGE: &str = "\
+usage:
+    aoc list
+    aoc run <year> <day> [part] [--input FILE] [--threads N | --stream]
+    aoc explain <year> <day> [--input FILE] [--disagreements | --absent POLICY]
+    aoc possible <year> <day> [--input FILE] [--bag CUBES]
+    aoc input add <year> <day> [FILE]
+    aoc input path <year> <day>
+    aoc input show <year> <day>
+    aoc fetch <year> <day>
+    aoc submit <year> <day> <part> [answer]
+    aoc verify
+    aoc bench [year [day]] [--iterations N] [--threshold PERCENT] [--no-save]
+
+Without --input, `run` uses the stored input for that day, or stdin when
+none is stored and something is piped in; `--input -` always reads stdin.
+`--threads N` splits the input into N chunks of lines solved side by side,
+at most one per core (only in builds with the `parallel` feature). `--stream` reads the input a
+line at a time instead of loading it whole, for inputs too large to keep in
+memory.
+`explain` traces how each line of a 2023 day 1 input is read: the first and
+last digit, their byte offsets and the calibration value, with part one's
+reading added where it differs (only those lines with --disagreements). For
+2023 day 2 it lists each game's minimum bag and power, and their sum; a
+color a game never shows counts as 0 cubes (--absent zero, the puzzle's
+reading), is left out of the power (--absent one) or leaves the game without
+one (--absent report). It finds its input like `run`.
+`possible` lists the 2023 day 2 games that a bag could have produced, and
+the sum of their ids. The bag is written like a round, as in
+--bag \"12 red, 13 green, 14 blue\" (the bag of part one, and the default).
+`input add` reads stdin when no FILE is given. `fetch` downloads an input
+into the store and `submit` sends an answer, computing it from the stored
+input when none is given; both need a session cookie in $AOC_SESSION or as
+`session = ...` (or `session.<profile> = ...`) in <data dir>/config.
+Arguments after `--` are never read as options, so an answer like -5 can
+also be written `submit 2023 1 1 -- -5`.
+
+Accepted answers are recorded in ./answers.tsv (or --answers FILE, or
+$AOC_ANSWERS), keyed by input checksum; `verify` re-solves every day with a
+stored input and reports answers that changed. Rejected guesses are kept
+per profile and are never submitted again.
+
+`bench` times the parse step and both parts of every selected day with a
+stored input (100 iterations by default), appends the results to
+<data dir>/bench-history.tsv and flags medians that got slower than the
+previous run by more than the threshold (10% by default).
+
+Inputs are stored per profile under the data directory. Commands that use
+stored inputs accept --data-dir DIR and --profile NAME, defaulting to
+$AOC_DATA_DIR (or ./data) and $AOC_PROFILE (or \"default\").";
+
+fn main() -> ExitCode {
+    let mut argv = std::env::args().skip(1);
+    let command = argv.next();
+    let args = Args::new(argv.collect());
+
+    let result = match command.as_deref() {
+        Some("list") => list(args),
+        Some("run") => run(args),
+        Some("explain") => explain(args),
+        Some("possible") => possible(args),
+        Some("input") => input(args),
+        Some("fetch") => fetch(args),
+        Some("submit") => submit(args),
+        Some("verify") => verify(args),
+        Some("bench") => bench(args),
+        Some("help" | "--help" | "-h") => {
+            println!("{USAGE}");
+            Ok(())
+        }
+        Some(other) => Err(Error::Usage(format!("unknown command {other:?}"))),
+        None => Err(Error::Usage("missing command".to_string())),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            if let Error::Usage(_) = error {
+                eprintln!("\n{USAGE}");
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn list(args: Args) -> Result<(), Error> {
+    args.positional()?;
+
+    for day in registry::days() {
+        println!("{} day {:>2}: {}", day.year, day.day, day.title);
+    }
+
+    Ok(())
+}
+
+fn run(mut args: Args) -> Result<(), Error> {
+    let input_path = args.option("--input")?;
+    let threads = match args.option("--threads")? {
+        Some(threads) => Some(parse_threads(&threads)?),
+        None => None,
+    };
+    let stream = args.flag("--stream");
+    if stream && threads.is_some() {
+        return Err(Error::Usage(
+            "--stream and --threads cannot be combined".to_string(),
+        ));
+    }
+    let store = open_store(&mut args)?;
+    let positional = args.positional()?;
+
+    let (year, day, parts) = match positional.as_slice() {
+        [year, day] => (year, day, Part::ALL.to_vec()),
+        [year, day, part] => (year, day, vec![part.parse().map_err(Error::Usage)?]),
+        _ => return Err(Error::Usage("run expects <year> <day> [part]".to_string())),
+    };
+    let year = parse_number(year, "year")?;
+    let day = parse_number(day, "day")?;
+
+    // Fail on an unknown day before blocking on stdin.
+    registry::find(year, day)?;
+    if stream {
+        let path = match input_path {
+            Some(path) => path,
+            None => {
+                let stored = store.input_path(year, day);
+                if stored.exists() {
+                    stored.display().to_string()
+                } else if !io::stdin().is_terminal() && !io::stdin().lock().fill_buf()?.is_empty() {
+                    "-".to_string()
+                } else {
+                    return Err(Error::MissingInput {
+                        year,
+                        day,
+                        profile: store.profile().to_string(),
+                    });
+                }
+            }
+        };
+        let answers = match path.as_str() {
+            "-" => aoc::run_reader(year, day, &parts, &mut io::stdin().lock())?,
+            path => {
+                let file = fs::File::open(path).map_err(|error| Error::File {
+                    path: path.into(),
+                    error,
+                })?;
+                aoc::run_reader(year, day, &parts, &mut BufReader::new(file))?
+            }
+        };
+        for (part, answer) in answers {
+            println!("part {part}: {answer}");
+        }
+
+        return Ok(());
+    }
+    let input = load_input(&store, input_path, year, day)?;
+
+    let answers = match threads {
+        #[cfg(feature = "parallel")]
+        Some(threads) => aoc::run_parallel(year, day, &parts, &input, threads)?,
+        _ => aoc::run(year, day, &parts, &input)?,
+    };
+    for (part, answer) in answers {
+        println!("part {part}: {answer}");
+    }
+
+    Ok(())
+}
+
+fn explain(mut args: Args) -> Result<(), Error> {
+    let input_path = args.option("--input")?;
+    let disagreements = args.flag("--disagreements");
+    let absent = args.option("--absent")?;
+    let store = open_store(&mut args)?;
+    let positional = args.positional()?;
+    let [year, day] = positional.as_slice() else {
+        return Err(Error::Usage("explain expects <year> <day>".to_string()));
+    };
+    let year = parse_number(year, "year")?;
+    let day = parse_number(day, "day")?;
+
+    registry::find(year, day)?;
+    match (year, day) {
+        (2023, 1) if absent.is_none() => {}
+        (2023, 2) if !disagreements => {}
+        (2023, 1 | 2) => {
+            let option = if disagreements {
+                "--disagreements"
+            } else {
+                "--absent"
+            };
+            return Err(Error::Usage(format!(
+                "{option} does not apply to {year} day {day}"
+            )));
+        }
+        _ => {
+            return Err(Error::Usage(format!(
+                "explain only knows 2023 days 1 and 2, not {year} day {day}"
+            )))
+        }
+    }
+    let input = load_input(&store, input_path, year, day)?;
+
+    if day == 1 {
+        let show = if disagreements {
+            Show::Disagreements
+        } else {
+            Show::All
+        };
+        for trace in trebuchet::explain::explain(&input, show) {
+            println!("{:>5}: {trace}", trace.number);
+        }
+        return Ok(());
+    }
+
+    let absent = match absent.as_deref() {
+        None | Some("zero") => Absent::Zero,
+        Some("one") => Absent::One,
+        Some("report") => Absent::Report,
+        Some(other) => {
+            return Err(Error::Usage(format!(
+                "--absent expects zero, one or report, not {other:?}"
+            )))
+        }
+    };
+    let games = cube_conundrum::parse_with(&input, &mut Palette::rgb(), Duplicates::Error)
+        .map_err(|error| Error::Parse {
+            snippet: error.snippet(&input),
+            error,
+        })?;
+    let reports = cube_conundrum::power_report(&games, &Palette::rgb(), absent);
+    for report in &reports {
+        println!("{report}");
+    }
+    let powers: Result<Vec<Natural>, _> = reports.into_iter().map(|report| report.power).collect();
+    match powers {
+        Ok(powers) => println!("sum: {}", Natural::sum(powers).map_err(Error::Overflow)?),
+        Err(_) => println!("sum: none, as some games have no power"),
+    }
+
+    Ok(())
+}
+
+fn possible(mut args: Args) -> Result<(), Error> {
+    let input_path = args.option("--input")?;
+    let bag = args.option("--bag")?;
+    let store = open_store(&mut args)?;
+    let positional = args.positional()?;
+    let [year, day] = positional.as_slice() else {
+        return Err(Error::Usage("possible expects <year> <day>".to_string()));
+    };
+    let year = parse_number(year, "year")?;
+    let day = parse_number(day, "day")?;
+
+    registry::find(year, day)?;
+    if (year, day) != (2023, 2) {
+        return Err(Error::Usage(format!(
+            "possible only knows 2023 day 2, not {year} day {day}"
+        )));
+    }
+    // Colors are discovered rather than fixed, so that variants of the game
+    // with other cubes work too. The bag may only name colors the games use
+    // or the puzzle's own, which catches a misspelt one.
+    let input = load_input(&store, input_path, year, day)?;
+    let mut palette = Palette::discover();
+    let games =
+        cube_conundrum::parse_with(&input, &mut palette, Duplicates::Error).map_err(|error| {
+            Error::Parse {
+                snippet: error.snippet(&input),
+                error,
+            }
+        })?;
+    let bag = match bag {
+        Some(bag) => Bag::parse_for(&bag, &palette).map_err(|error| Error::Parse {
+            snippet: error.snippet(&bag),
+            error,
+        })?,
+        None => Bag::puzzle(),
+    };
+
+    let ids = cube_conundrum::possible_games(&games, &bag);
+    let listed: Vec<String> = ids.iter().map(usize::to_string).collect();
+    println!("games: {}", listed.join(", "));
+    println!("sum:   {}", Natural::sum(ids).map_err(Error::Overflow)?);
+
+    Ok(())
+}
+
+/// The input at `path`, or else the stored one, or else stdin when something
+/// non-empty is piped in.
+fn load_input(store: &Store, path: Option<String>, year: u16, day: u8) -> Result<String, Error> {
+    match path {
+        Some(path) => read_input(&path),
+        None => match store.read(year, day) {
+            // An empty pipe, as from `< /dev/null`, is no input at all.
+            Err(missing @ Error::MissingInput { .. }) if !io::stdin().is_terminal() => {
+                match read_input("-")? {
+                    input if input.is_empty() => Err(missing),
+                    input => Ok(input),
+                }
+            }
+            stored => stored,
+        },
+    }
+}
+
+fn parse_threads(value: &str) -> Result<usize, Error> {
+    if cfg!(not(feature = "parallel")) {
+        return Err(Error::Usage(
+            "--threads needs aoc built with the parallel feature".to_string(),
+        ));
+    }
+
+    match parse_number(value, "thread count")? {
+        0 => Err(Error::Usage("--threads must be at least 1".to_string())),
+        threads => Ok(threads),
+    }
+}
+
+fn input(mut args: Args) -> Result<(), Error> {
+    let store = open_store(&mut args)?;
+    let positional = args.positional()?;
+
+    match positional.as_slice() {
+        [action, year, day, rest @ ..] => {
+            let year = parse_number(year, "year")?;
+            let day = parse_number(day, "day")?;
+            // Fail on an unknown day before blocking on stdin.
+            registry::find(year, day)?;
+
+            match (action.as_str(), rest) {
+                ("add", []) => add_input(&store, year, day, "-"),
+                ("add", [file]) => add_input(&store, year, day, file),
+                ("path", []) => {
+                    println!("{}", store.input_path(year, day).display());
+                    Ok(())
+                }
+                ("show", []) => {
+                    let input = store.read(year, day)?;
+                    println!("path:     {}", store.input_path(year, day).display());
+                    println!("profile:  {}", store.profile());
+                    println!("lines:    {}", input.lines().count());
+                    println!("bytes:    {}", input.len());
+                    println!("checksum: {}", store::checksum(&input));
+                    Ok(())
+                }
+                _ => Err(Error::Usage(format!("unknown input command {action:?}"))),
+            }
+        }
+        _ => Err(Error::Usage(
+            "input expects add|path|show <year> <day>".to_string(),
+        )),
+    }
+}
+
+fn add_input(store: &Store, year: u16, day: u8, source: &str) -> Result<(), Error> {
+    let input = read_input(source)?;
+    let path = store.add(year, day, &input)?;
+    println!(
+        "stored {year} day {day} ({}) at {}",
+        store::checksum(&input),
+        path.display()
+    );
+
+    Ok(())
+}
+
+fn fetch(mut args: Args) -> Result<(), Error> {
+    let store = open_store(&mut args)?;
+    let positional = args.positional()?;
+    let [year, day] = positional.as_slice() else {
+        return Err(Error::Usage("fetch expects <year> <day>".to_string()));
+    };
+    let year = parse_number(year, "year")?;
+    let day = parse_number(day, "day")?;
+
+    let input = Remote::new(Curl, &store)?.fetch(year, day)?;
+    println!(
+        "{year} day {day} ({}) is at {}",
+        store::checksum(&input),
+        store.input_path(year, day).display()
+    );
+
+    Ok(())
+}
+
+fn submit(mut args: Args) -> Result<(), Error> {
+    let store = open_store(&mut args)?;
+    let mut ledger = open_ledger(&mut args)?;
+    let positional = args.positional()?;
+    let (year, day, part, answer) = match positional.as_slice() {
+        [year, day, part] => (year, day, part, None),
+        [year, day, part, answer] => (year, day, part, Some(answer.clone())),
+        _ => {
+            return Err(Error::Usage(
+                "submit expects <year> <day> <part> [answer]".to_string(),
+            ))
+        }
+    };
+    let year = parse_number(year, "year")?;
+    let day = parse_number(day, "day")?;
+    let part: Part = part.parse().map_err(Error::Usage)?;
+
+    let remote = Remote::new(Curl, &store)?;
+    let input = remote.fetch(year, day)?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => aoc::run(year, day, &[part], &input)?[0].1.to_string(),
+    };
+
+    let key = Key::new(year, day, part, &input);
+    match ledger.accepted(&key) {
+        Some(accepted) if accepted == answer => {
+            println!("{answer} was already accepted");
+            return Ok(());
+        }
+        Some(accepted) => {
+            return Err(Error::KnownWrong {
+                answer,
+                reason: format!("{accepted} was already accepted"),
+            })
+        }
+        None => {}
+    }
+    let mut guesses = Guesses::load(&store)?;
+    guesses.vet(&key, &answer)?;
+
+    match remote.submit(year, day, part, &answer)? {
+        Verdict::Correct => {
+            ledger.record(key, &answer)?;
+            println!("{answer} is the right answer!");
+        }
+        Verdict::Incorrect { hint, wait } => {
+            guesses.record(key, &answer, hint)?;
+            let hint = match hint {
+                Some(Hint::TooHigh) => " (too high)",
+                Some(Hint::TooLow) => " (too low)",
+                None => "",
+            };
+            println!(
+                "{answer} is not the right answer{hint}; wait {}s before trying again",
+                wait.as_secs()
+            );
+        }
+        Verdict::TooSoon { wait } => println!(
+            "answered too recently, {answer} was not checked; wait {}s",
+            wait.as_secs()
+        ),
+        Verdict::WrongLevel => println!("part {part} is already solved or not unlocked yet"),
+    }
+
+    Ok(())
+}
+
+fn verify(mut args: Args) -> Result<(), Error> {
+    let store = open_store(&mut args)?;
+    let ledger = open_ledger(&mut args)?;
+    args.positional()?;
+
+    let mut failures = 0;
+    for check in ledger::verify(&store, &ledger) {
+        let label = format!("{} day {:>2} part {}", check.year, check.day, check.part);
+        match check.status {
+            Status::Match => println!("{label}: ok"),
+            Status::Mismatch { expected, actual } => {
+                failures += 1;
+                println!("{label}: MISMATCH, got {actual} but {expected} was accepted");
+            }
+            Status::Unrecorded { actual } => println!("{label}: {actual} (no accepted answer)"),
+            Status::Failed(error) => {
+                failures += 1;
+                println!("{label}: FAILED, {error}");
+            }
+        }
+    }
+
+    match failures {
+        0 => Ok(()),
+        _ => Err(Error::Verification { failures }),
+    }
+}
+
+fn bench(mut args: Args) -> Result<(), Error> {
+    let store = open_store(&mut args)?;
+    let iterations = match args.option("--iterations")? {
+        Some(value) => parse_number(&value, "--iterations")?,
+        None => 100,
+    };
+    let threshold: f64 = match args.option("--threshold")? {
+        Some(value) => parse_number(&value, "--threshold")?,
+        None => 10.0,
+    };
+    let save = !args.flag("--no-save");
+    let positional = args.positional()?;
+
+    let (year, day) = match positional.as_slice() {
+        [] => (None, None),
+        [year] => (Some(parse_number::<u16>(year, "year")?), None),
+        [year, day] => (
+            Some(parse_number::<u16>(year, "year")?),
+            Some(parse_number::<u8>(day, "day")?),
+        ),
+        _ => return Err(Error::Usage("bench expects [year [day]]".to_string())),
+    };
+    if let (Some(year), Some(day)) = (year, day) {
+        registry::find(year, day)?;
+    }
+
+    let history_path = History::path(&store);
+    let history = History::load(&history_path)?;
+    let mut results = Vec::new();
+
+    for entry in registry::days() {
+        if year.is_some_and(|year| year != entry.year) || day.is_some_and(|day| day != entry.day) {
+            continue;
+        }
+        let input = match store.read(entry.year, entry.day) {
+            Err(Error::MissingInput { .. }) => {
+                println!(
+                    "{} day {:>2}: no stored input, skipped",
+                    entry.year, entry.day
+                );
+                continue;
+            }
+            input => input?,
+        };
+
+        for measurement in bench::measure(entry, &input, iterations)? {
+            let stats = measurement.stats;
+            let mut line = format!(
+                "{} day {:>2} {:<6} mean {:>10.2?}  median {:>10.2?}  min {:>10.2?}  stddev {:>10.2?}",
+                measurement.year,
+                measurement.day,
+                measurement.stage.to_string(),
+                stats.mean,
+                stats.median,
+                stats.min,
+                stats.stddev
+            );
+            if let Some(previous) = history.previous(&measurement) {
+                let change = bench::slowdown(previous, &measurement) * 100.0;
+                line.push_str(&format!("  {change:+6.1}%"));
+                if change > threshold {
+                    line.push_str("  SLOWER");
+                }
+            }
+            println!("{line}");
+            results.push(measurement);
+        }
+    }
+
+    if save && !results.is_empty() {
+        History::append(&history_path, &results)?;
+    }
+
+    Ok(())
+}
+
+fn open_ledger(args: &mut Args) -> Result<Ledger, Error> {
+    let path = match args.option("--answers")? {
+        Some(path) => path.into(),
+        None => std::env::var_os("AOC_ANSWERS").map_or(
+            ledger::DEFAULT_ANSWERS_FILE.into(),
+            std::path::PathBuf::from,
+        ),
+    };
+
+    Ledger::load(path)
+}
+
+fn open_store(args: &mut Args) -> Result<Store, Error> {
+    let default = Store::from_env()?;
+    let root = args
+        .option("--data-dir")?
+        .map_or_else(|| default.root().to_path_buf(), Into::into);
+    let profile = args
+        .option("--profile")?
+        .unwrap_or_else(|| default.profile().to_string());
+
+    Store::new(root, profile)
+}
+
+/// Reads the file at `path`, or stdin for `-`.
+fn read_input(path: &str) -> Result<String, Error> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
+    }
+
+    fs::read_to_string(path).map_err(|error| Error::File {
+        path: path.into(),
+        error,
+    })
+}
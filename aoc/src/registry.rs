@@ -0,0 +1,52 @@
+//! Every solved day across all years.
+
+use crate::Error;
+use aoc_core::Day;
+
+/// All registered days, ordered by year and then by day.
+pub fn days() -> impl Iterator<Item = &'static Day> {
+    aoc2023::DAYS.iter()
+}
+
+/// Looks up a single day, telling apart unsolved puzzles from dates that
+/// never had one.
+pub fn find(year: u16, day: u8) -> Result<&'static Day, Error> {
+    if year < 2015 || !(1..=25).contains(&day) {
+        return Err(Error::NoSuchDay { year, day });
+    }
+
+    days()
+        .find(|entry| entry.year == year && entry.day == day)
+        .ok_or(Error::NotImplemented { year, day })
+}
+
+mod test {
+    #[cfg(test)]
+    mod find {
+        use super::super::find;
+        use crate::Error;
+
+        #[test]
+        fn test_registered_days() {
+            assert_eq!(find(2023, 1).unwrap().title, "Trebuchet?!");
+            assert_eq!(find(2023, 2).unwrap().title, "Cube Conundrum");
+        }
+
+        #[test]
+        fn test_unsolved_day() {
+            assert!(matches!(
+                find(2023, 25),
+                Err(Error::NotImplemented {
+                    year: 2023,
+                    day: 25
+                })
+            ));
+        }
+
+        #[test]
+        fn test_invalid_day() {
+            assert!(matches!(find(2023, 26), Err(Error::NoSuchDay { .. })));
+            assert!(matches!(find(2014, 1), Err(Error::NoSuchDay { .. })));
+        }
+    }
+}
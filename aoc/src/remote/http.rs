@@ -0,0 +1,125 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use crate::Error;
+
+/// What the remote end sent back.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The transport used to talk to adventofcode.com.
+///
+/// The real implementation is [`Curl`]; tests use an in-process mock so the
+/// suite never touches the network.
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<Response, Error>;
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)])
+        -> Result<Response, Error>;
+}
+
+/// Sends requests through the system `curl`, which handles TLS for us.
+pub struct Curl;
+
+const USER_AGENT: &str = "github.com/polyecho/advent-of-code aoc-cli";
+
+impl Curl {
+    fn send(&self, url: &str, session: &str, extra: &[String]) -> Result<Response, Error> {
+        let (mut command, config) = Curl::command(url, session, extra);
+        let mut child = command
+            .spawn()
+            .map_err(|error| Error::Remote(format!("cannot run curl: {error}")))?;
+        // The cookie goes through stdin so that it never shows up in the
+        // process list.
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(config.as_bytes()).map_err(|error| {
+                Error::Remote(format!("cannot pass the session to curl: {error}"))
+            })?;
+        }
+        let output = child
+            .wait_with_output()
+            .map_err(|error| Error::Remote(format!("cannot run curl: {error}")))?;
+
+        if !output.status.success() {
+            return Err(Error::Remote(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout.rsplit_once('\n').unwrap_or(("", &stdout));
+        let status = status
+            .trim()
+            .parse()
+            .map_err(|_| Error::Remote(format!("curl reported status {status:?}")))?;
+
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+
+    /// The curl invocation for `url` and the config it reads from stdin,
+    /// which carries the session cookie.
+    fn command(url: &str, session: &str, extra: &[String]) -> (Command, String) {
+        let mut command = Command::new("curl");
+        command
+            .args(["--silent", "--show-error", "--user-agent", USER_AGENT])
+            .args(["--config", "-"])
+            .args(["--write-out", "\n%{http_code}"])
+            .args(extra)
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let session = session.replace('\\', "\\\\").replace('"', "\\\"");
+        let config = format!("header = \"Cookie: session={session}\"\n");
+
+        (command, config)
+    }
+}
+
+impl Http for Curl {
+    fn get(&self, url: &str, session: &str) -> Result<Response, Error> {
+        self.send(url, session, &[])
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, Error> {
+        let fields: Vec<String> = form
+            .iter()
+            .flat_map(|(name, value)| ["--data-urlencode".to_string(), format!("{name}={value}")])
+            .collect();
+
+        self.send(url, session, &fields)
+    }
+}
+
+mod test {
+    #[cfg(test)]
+    mod curl {
+        use super::super::Curl;
+
+        #[test]
+        fn test_session_stays_off_the_command_line() {
+            let form = ["--data-urlencode".to_string(), "answer=281".to_string()];
+            let url = "https://adventofcode.com/2023/day/1/answer";
+            let (command, config) = Curl::command(url, "53cr3t", &form);
+
+            assert!(command
+                .get_args()
+                .all(|arg| !arg.to_string_lossy().contains("53cr3t")));
+            assert_eq!(config, "header = \"Cookie: session=53cr3t\"\n");
+
+            let (_, config) = Curl::command(url, "a\"b\\c", &[]);
+            assert_eq!(config, "header = \"Cookie: session=a\\\"b\\\\c\"\n");
+        }
+    }
+}
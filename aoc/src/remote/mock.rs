@@ -0,0 +1,88 @@
+//! An in-process stand-in for adventofcode.com used by the test suite.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use super::{Http, Response};
+use crate::Error;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    pub method: &'static str,
+    pub url: String,
+    pub session: String,
+    pub form: Vec<(String, String)>,
+}
+
+/// Serves canned responses by URL and records every request it receives.
+#[derive(Default)]
+pub struct MockServer {
+    routes: HashMap<String, Vec<Response>>,
+    requests: RefCell<Vec<Request>>,
+}
+
+impl MockServer {
+    /// Queues `body` as the next response for `url`; the last queued response
+    /// for a URL keeps being served once the others are used up.
+    pub fn respond(&mut self, url: &str, status: u16, body: &str) -> &mut Self {
+        self.routes
+            .entry(url.to_string())
+            .or_default()
+            .push(Response {
+                status,
+                body: body.to_string(),
+            });
+        self
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.borrow().clone()
+    }
+
+    fn serve(&self, request: Request) -> Result<Response, Error> {
+        let served = self
+            .requests
+            .borrow()
+            .iter()
+            .filter(|earlier| earlier.url == request.url)
+            .count();
+        let response = match self.routes.get(&request.url) {
+            Some(responses) => responses[served.min(responses.len() - 1)].clone(),
+            None => Response {
+                status: 404,
+                body: "404 Not Found".to_string(),
+            },
+        };
+        self.requests.borrow_mut().push(request);
+
+        Ok(response)
+    }
+}
+
+impl Http for &MockServer {
+    fn get(&self, url: &str, session: &str) -> Result<Response, Error> {
+        self.serve(Request {
+            method: "GET",
+            url: url.to_string(),
+            session: session.to_string(),
+            form: Vec::new(),
+        })
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, Error> {
+        self.serve(Request {
+            method: "POST",
+            url: url.to_string(),
+            session: session.to_string(),
+            form: form
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,290 @@
+//! Talking to adventofcode.com: downloading inputs and submitting answers.
+//!
+//! Requests are authenticated with the session cookie of a logged-in browser,
+//! read from `$AOC_SESSION` or from the `session` key of the data directory's
+//! config file. Submissions respect the site's cooldowns locally, so a
+//! rejected answer does not lead to hammering the server.
+
+mod http;
+#[cfg(test)]
+mod mock;
+pub mod verdict;
+
+use std::fs;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub use http::{Curl, Http, Response};
+pub use verdict::{Hint, Verdict};
+
+use crate::{registry, Error, Store};
+use aoc_core::Part;
+
+pub struct Remote<'a, H> {
+    http: H,
+    store: &'a Store,
+    session: String,
+}
+
+impl<'a, H: Http> Remote<'a, H> {
+    /// Uses the session cookie configured for `store`'s profile.
+    pub fn new(http: H, store: &'a Store) -> Result<Self, Error> {
+        let session = match std::env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => store
+                .config(&format!("session.{}", store.profile()))?
+                .or(store.config("session")?)
+                .ok_or(Error::MissingSession)?,
+        };
+
+        Ok(Remote::with_session(http, store, session))
+    }
+
+    pub fn with_session(http: H, store: &'a Store, session: impl Into<String>) -> Self {
+        Remote {
+            http,
+            store,
+            session: session.into(),
+        }
+    }
+
+    /// Returns the input for `year`/`day`, downloading it into the store the
+    /// first time it is needed.
+    pub fn fetch(&self, year: u16, day: u8) -> Result<String, Error> {
+        match self.store.read(year, day) {
+            Err(Error::MissingInput { .. }) => {}
+            stored => return stored,
+        }
+
+        let url = format!("{}/input", puzzle_url(year, day)?);
+        let response = self.http.get(&url, &self.session)?;
+        if response.status != 200 {
+            return Err(Error::Remote(format!(
+                "GET {url} returned {}: {}",
+                response.status,
+                response.body.trim()
+            )));
+        }
+
+        self.store.add(year, day, &response.body)?;
+        Ok(response.body)
+    }
+
+    /// Submits `answer` for `part`, refusing to do so while a cooldown from an
+    /// earlier submission is still running.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Verdict, Error> {
+        let url = format!("{}/answer", puzzle_url(year, day)?);
+
+        if let Some(remaining) = self.cooldown()? {
+            return Err(Error::Cooldown { remaining });
+        }
+
+        let level = part.to_string();
+        let response = self.http.post_form(
+            &url,
+            &self.session,
+            &[("level", level.as_str()), ("answer", answer)],
+        )?;
+        if response.status != 200 {
+            return Err(Error::Remote(format!(
+                "POST {url} returned {}",
+                response.status
+            )));
+        }
+
+        let verdict = verdict::parse(&response.body).ok_or_else(|| {
+            Error::Remote("could not understand the response to the submission".to_string())
+        })?;
+        if let Verdict::Incorrect { wait, .. } | Verdict::TooSoon { wait } = verdict {
+            self.start_cooldown(wait)?;
+        }
+
+        Ok(verdict)
+    }
+
+    /// How long until the next submission is allowed, if it is not yet.
+    pub fn cooldown(&self) -> Result<Option<Duration>, Error> {
+        let path = self.store.state_path("cooldown");
+        let until = match fs::read_to_string(&path) {
+            Ok(contents) => contents.trim().parse::<u64>().unwrap_or(0),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+
+        let remaining = Duration::from_secs(until).saturating_sub(now());
+        Ok((!remaining.is_zero()).then_some(remaining))
+    }
+
+    fn start_cooldown(&self, wait: Duration) -> Result<(), Error> {
+        let path = self.store.state_path("cooldown");
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, (now() + wait).as_secs().to_string())?;
+
+        Ok(())
+    }
+}
+
+/// The puzzle page for a day, as recorded in its solution's header when the
+/// day is solved already.
+fn puzzle_url(year: u16, day: u8) -> Result<String, Error> {
+    match registry::find(year, day) {
+        Ok(entry) => Ok(entry
+            .url()
+            .map_or_else(|| default_url(year, day), str::to_string)),
+        Err(Error::NotImplemented { .. }) => Ok(default_url(year, day)),
+        Err(error) => Err(error),
+    }
+}
+
+fn default_url(year: u16, day: u8) -> String {
+    format!("https://adventofcode.com/{year}/day/{day}")
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+mod test {
+    #[cfg(test)]
+    mod fetch {
+        use super::super::mock::MockServer;
+        use super::super::Remote;
+        use crate::store::{temp_dir, Store};
+        use crate::Error;
+
+        #[test]
+        fn test_downloads_once() {
+            let root = temp_dir("fetch");
+            let store = Store::new(root.path(), "default").unwrap();
+            let mut server = MockServer::default();
+            server.respond("https://adventofcode.com/2023/day/1/input", 200, "1abc2\n");
+            let remote = Remote::with_session(&server, &store, "cookie");
+
+            assert_eq!(remote.fetch(2023, 1).unwrap(), "1abc2\n");
+            assert_eq!(remote.fetch(2023, 1).unwrap(), "1abc2\n");
+            assert_eq!(store.read(2023, 1).unwrap(), "1abc2\n");
+
+            let requests = server.requests();
+            assert_eq!(requests.len(), 1);
+            assert_eq!(requests[0].method, "GET");
+            assert_eq!(requests[0].session, "cookie");
+        }
+
+        #[test]
+        fn test_unsolved_day() {
+            let root = temp_dir("fetch");
+            let store = Store::new(root.path(), "default").unwrap();
+            let mut server = MockServer::default();
+            server.respond("https://adventofcode.com/2023/day/25/input", 200, "x\n");
+            let remote = Remote::with_session(&server, &store, "cookie");
+
+            assert_eq!(remote.fetch(2023, 25).unwrap(), "x\n");
+        }
+
+        #[test]
+        fn test_logged_out() {
+            let root = temp_dir("fetch");
+            let store = Store::new(root.path(), "default").unwrap();
+            let mut server = MockServer::default();
+            server.respond(
+                "https://adventofcode.com/2023/day/2/input",
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            );
+            let remote = Remote::with_session(&server, &store, "expired");
+
+            assert!(matches!(remote.fetch(2023, 2), Err(Error::Remote(_))));
+            assert!(matches!(
+                store.read(2023, 2),
+                Err(Error::MissingInput { .. })
+            ));
+        }
+    }
+
+    #[cfg(test)]
+    mod submit {
+        use super::super::mock::MockServer;
+        use super::super::{Hint, Remote, Verdict};
+        use crate::store::{temp_dir, Store};
+        use crate::Error;
+        use aoc_core::Part;
+        use std::time::Duration;
+
+        const ANSWER_URL: &str = "https://adventofcode.com/2023/day/1/answer";
+
+        #[test]
+        fn test_correct() {
+            let root = temp_dir("submit");
+            let store = Store::new(root.path(), "default").unwrap();
+            let mut server = MockServer::default();
+            server.respond(
+                ANSWER_URL,
+                200,
+                "<article><p>That's the right answer!  You are one gold star closer.</p></article>",
+            );
+            let remote = Remote::with_session(&server, &store, "cookie");
+
+            assert_eq!(
+                remote.submit(2023, 1, Part::Two, "281").unwrap(),
+                Verdict::Correct
+            );
+
+            let form = &server.requests()[0].form;
+            assert_eq!(
+                form,
+                &vec![
+                    ("level".to_string(), "2".to_string()),
+                    ("answer".to_string(), "281".to_string()),
+                ]
+            );
+            assert_eq!(remote.cooldown().unwrap(), None);
+        }
+
+        #[test]
+        fn test_wrong_answer_starts_cooldown() {
+            let root = temp_dir("submit");
+            let store = Store::new(root.path(), "default").unwrap();
+            let mut server = MockServer::default();
+            server.respond(
+                ANSWER_URL,
+                200,
+                "<article><p>That's not the right answer; your answer is too high.  Please wait one minute before trying again.</p></article>",
+            );
+            let remote = Remote::with_session(&server, &store, "cookie");
+
+            assert_eq!(
+                remote.submit(2023, 1, Part::One, "999").unwrap(),
+                Verdict::Incorrect {
+                    hint: Some(Hint::TooHigh),
+                    wait: Duration::from_secs(60),
+                }
+            );
+            assert!(matches!(
+                remote.submit(2023, 1, Part::One, "998"),
+                Err(Error::Cooldown { .. })
+            ));
+            assert_eq!(server.requests().len(), 1);
+        }
+
+        #[test]
+        fn test_too_soon_starts_cooldown() {
+            let root = temp_dir("submit");
+            let store = Store::new(root.path(), "default").unwrap();
+            let mut server = MockServer::default();
+            server.respond(
+                ANSWER_URL,
+                200,
+                "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 30s left to wait.</p></article>",
+            );
+            let remote = Remote::with_session(&server, &store, "cookie");
+
+            remote.submit(2023, 1, Part::One, "142").unwrap();
+            let remaining = remote.cooldown().unwrap().unwrap();
+
+            assert!(remaining <= Duration::from_secs(30));
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//! Reading the page adventofcode.com returns after an answer is submitted.
+
+use std::time::Duration;
+
+/// Whether a wrong answer was above or below the right one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// The answer was wrong; no new guess is accepted until `wait` has passed.
+    Incorrect {
+        hint: Option<Hint>,
+        wait: Duration,
+    },
+    /// A previous submission was too recent, so this one was not checked.
+    TooSoon {
+        wait: Duration,
+    },
+    /// The part has already been solved, or is not unlocked yet.
+    WrongLevel,
+}
+
+/// Wait applied to a wrong answer when the page does not say otherwise.
+const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+/// Interprets the HTML of an answer page, or `None` if it is unrecognizable.
+pub fn parse(page: &str) -> Option<Verdict> {
+    let text = page_text(page);
+
+    if text.contains("That's the right answer") {
+        return Some(Verdict::Correct);
+    }
+    if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| parse_left_to_wait(rest))
+            .unwrap_or(DEFAULT_WAIT);
+        return Some(Verdict::TooSoon { wait });
+    }
+    if text.contains("That's not the right answer") {
+        let hint = if text.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        let wait = text
+            .to_lowercase()
+            .split_once("please wait ")
+            .and_then(|(_, rest)| parse_please_wait(rest))
+            .unwrap_or(DEFAULT_WAIT);
+        return Some(Verdict::Incorrect { hint, wait });
+    }
+    if text.contains("You don't seem to be solving the right level") {
+        return Some(Verdict::WrongLevel);
+    }
+
+    None
+}
+
+/// The text of the page's `<article>`, without tags and with spaces collapsed.
+fn page_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses "4m 32s left to wait" or "32s left to wait".
+fn parse_left_to_wait(text: &str) -> Option<Duration> {
+    let (amount, _) = text.split_once(" left to wait")?;
+    let mut seconds = 0;
+
+    for part in amount.split_whitespace() {
+        let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Parses "one minute before trying again" or "5 minutes before trying again".
+fn parse_please_wait(text: &str) -> Option<Duration> {
+    let mut words = text.split_whitespace();
+    let number = match words.next()? {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+
+    match words.next()? {
+        "minute" | "minutes" => Some(Duration::from_secs(number * 60)),
+        "second" | "seconds" => Some(Duration::from_secs(number)),
+        _ => None,
+    }
+}
+
+mod test {
+    #[cfg(test)]
+    mod parse {
+        use super::super::{parse, Hint, Verdict};
+        use std::time::Duration;
+
+        fn page(article: &str) -> String {
+            format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+        }
+
+        #[test]
+        fn test_correct() {
+            let html = page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.");
+
+            assert_eq!(parse(&html), Some(Verdict::Correct));
+        }
+
+        #[test]
+        fn test_too_high() {
+            let html = page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about page</a>.  Please wait one minute before trying again. [<a href=\"/2023/day/1\">Return to Day 1</a>]");
+
+            assert_eq!(
+                parse(&html),
+                Some(Verdict::Incorrect {
+                    hint: Some(Hint::TooHigh),
+                    wait: Duration::from_secs(60),
+                })
+            );
+        }
+
+        #[test]
+        fn test_too_low_with_longer_wait() {
+            let html = page("That's not the right answer; your answer is too low.  Because you have guessed incorrectly 5 times on this puzzle, please wait 5 minutes before trying again.");
+
+            assert_eq!(
+                parse(&html),
+                Some(Verdict::Incorrect {
+                    hint: Some(Hint::TooLow),
+                    wait: Duration::from_secs(300),
+                })
+            );
+        }
+
+        #[test]
+        fn test_wrong_without_hint() {
+            let html = page("That's not the right answer.  If you're stuck, make sure you're using the full input data.");
+
+            assert_eq!(
+                parse(&html),
+                Some(Verdict::Incorrect {
+                    hint: None,
+                    wait: Duration::from_secs(60),
+                })
+            );
+        }
+
+        #[test]
+        fn test_too_soon() {
+            let html = page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait. [<a href=\"/2023/day/1\">Return to Day 1</a>]");
+
+            assert_eq!(
+                parse(&html),
+                Some(Verdict::TooSoon {
+                    wait: Duration::from_secs(272),
+                })
+            );
+        }
+
+        #[test]
+        fn test_wrong_level() {
+            let html = page("You don't seem to be solving the right level.  Did you already complete it? [<a href=\"/2023/day/1\">Return to Day 1</a>]");
+
+            assert_eq!(parse(&html), Some(Verdict::WrongLevel));
+        }
+
+        #[test]
+        fn test_unknown_page() {
+            assert_eq!(parse(&page("Something else entirely.")), None);
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! Puzzle inputs kept on disk, keyed by year, day and profile.
+//!
+//! Everyone gets a different input, so inputs are filed under a profile name
+//! (one per person sharing the checkout). The layout is
+//! `<data dir>/inputs/<profile>/<year>/<day>.txt`, with the day zero-padded.
+
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+use crate::Error;
+
+pub const DEFAULT_DATA_DIR: &str = "data";
+pub const DEFAULT_PROFILE: &str = "default";
+
+pub struct Store {
+    root: PathBuf,
+    profile: String,
+}
+
+impl Store {
+    pub fn new(root: impl Into<PathBuf>, profile: impl Into<String>) -> Result<Self, Error> {
+        let profile = profile.into();
+
+        let valid = !profile.is_empty()
+            && profile
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(Error::Usage(format!(
+                "profile names may only use letters, digits, '-' and '_', got {profile:?}"
+            )));
+        }
+
+        Ok(Store {
+            root: root.into(),
+            profile,
+        })
+    }
+
+    /// Opens the store named by `AOC_DATA_DIR` and `AOC_PROFILE`, falling back
+    /// to `./data` and the default profile.
+    pub fn from_env() -> Result<Self, Error> {
+        let root = env::var_os("AOC_DATA_DIR").map_or(DEFAULT_DATA_DIR.into(), PathBuf::from);
+        let profile = env::var("AOC_PROFILE").unwrap_or_else(|_| DEFAULT_PROFILE.to_string());
+
+        Store::new(root, profile)
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn profile(&self) -> &str {
+        &self.profile
+    }
+
+    /// Where the input for `year`/`day` lives, whether or not it exists yet.
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.root
+            .join("inputs")
+            .join(&self.profile)
+            .join(year.to_string())
+            .join(format!("{day:02}.txt"))
+    }
+
+    /// Saves `input` for `year`/`day`, replacing any previous copy.
+    pub fn add(&self, year: u16, day: u8, input: &str) -> io::Result<PathBuf> {
+        let path = self.input_path(year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, input)?;
+
+        Ok(path)
+    }
+
+    /// A per-profile file for bookkeeping that is not an input.
+    pub fn state_path(&self, name: &str) -> PathBuf {
+        self.root.join("state").join(&self.profile).join(name)
+    }
+
+    /// Looks `key` up in `<data dir>/config`, a file of `key = value` lines
+    /// where `#` starts a comment.
+    pub fn config(&self, key: &str) -> Result<Option<String>, Error> {
+        let contents = match fs::read_to_string(self.root.join("config")) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+
+        Ok(contents
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or_default())
+            .filter_map(|line| line.split_once('='))
+            .find(|(name, _)| name.trim() == key)
+            .map(|(_, value)| value.trim().to_string()))
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<String, Error> {
+        let path = self.input_path(year, day);
+
+        fs::read_to_string(&path).map_err(|error| match error.kind() {
+            io::ErrorKind::NotFound => Error::MissingInput {
+                year,
+                day,
+                profile: self.profile.clone(),
+            },
+            _ => Error::Io(error),
+        })
+    }
+}
+
+/// A short fingerprint of an input, used to tell inputs apart.
+///
+/// Line endings and the final newline are ignored so that the same input saved
+/// on different machines gets the same checksum.
+pub fn checksum(input: &str) -> String {
+    // 64-bit FNV-1a.
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in input.trim_end().bytes().filter(|&byte| byte != b'\r') {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+
+    format!("{hash:016x}")
+}
+
+/// A fresh directory for a test, removed with everything in it once the
+/// test is done with it.
+#[cfg(test)]
+pub(crate) struct TempDir(PathBuf);
+
+#[cfg(test)]
+impl TempDir {
+    pub(crate) fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+impl std::ops::Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn temp_dir(name: &str) -> TempDir {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = env::temp_dir().join(format!("aoc-{name}-{}-{unique}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+
+    TempDir(dir)
+}
+
+mod test {
+    #[cfg(test)]
+    mod store {
+        use super::super::{temp_dir, Store};
+        use crate::Error;
+
+        #[test]
+        fn test_add_and_read() {
+            let root = temp_dir("store");
+            let store = Store::new(root.path(), "alice").unwrap();
+            let path = store.add(2023, 1, "1abc2\n").unwrap();
+
+            assert!(path.ends_with("inputs/alice/2023/01.txt"));
+            assert_eq!(store.read(2023, 1).unwrap(), "1abc2\n");
+        }
+
+        #[test]
+        fn test_temp_dir_is_removed() {
+            let root = temp_dir("removed");
+            let path = root.to_path_buf();
+            Store::new(root.path(), "alice")
+                .unwrap()
+                .add(2023, 1, "1abc2\n")
+                .unwrap();
+            assert!(path.exists());
+
+            drop(root);
+            assert!(!path.exists());
+        }
+
+        #[test]
+        fn test_profiles_are_separate() {
+            let root = temp_dir("profiles");
+            let alice = Store::new(root.path(), "alice").unwrap();
+            let bob = Store::new(root.path(), "bob").unwrap();
+            alice.add(2023, 2, "Game 1: 1 red").unwrap();
+
+            assert!(matches!(
+                bob.read(2023, 2),
+                Err(Error::MissingInput {
+                    year: 2023,
+                    day: 2,
+                    ..
+                })
+            ));
+        }
+
+        #[test]
+        fn test_config() {
+            let root = temp_dir("config");
+            std::fs::create_dir_all(root.path()).unwrap();
+            std::fs::write(
+                root.join("config"),
+                "# shared checkout\nsession = abc123\nsession.bob=def456 # bob's\n",
+            )
+            .unwrap();
+            let store = Store::new(root.path(), "bob").unwrap();
+
+            assert_eq!(store.config("session").unwrap().as_deref(), Some("abc123"));
+            assert_eq!(
+                store.config("session.bob").unwrap().as_deref(),
+                Some("def456")
+            );
+            assert_eq!(store.config("session.alice").unwrap(), None);
+        }
+
+        #[test]
+        fn test_invalid_profile() {
+            assert!(Store::new("data", "../elsewhere").is_err());
+            assert!(Store::new("data", "").is_err());
+        }
+    }
+
+    #[cfg(test)]
+    mod checksum {
+        use super::super::checksum;
+
+        #[test]
+        fn test_ignores_line_endings() {
+            assert_eq!(
+                checksum("1abc2\ntreb7uchet"),
+                checksum("1abc2\r\ntreb7uchet\r\n")
+            );
+            assert_ne!(checksum("1abc2\ntreb7uchet"), checksum("1abc2\ntreb8uchet"));
+        }
+
+        #[test]
+        fn test_known_value() {
+            assert_eq!(checksum(""), "cbf29ce484222325");
+            assert_eq!(checksum("a"), "af63dc4c8601ec8c");
+        }
+    }
+}